@@ -5,6 +5,8 @@ use worldstate_parser::{
     WorldState,
     default_context_provider::{DefaultContextProvider, PathContext},
     default_data_fetcher::CacheStrategy,
+    endpoints::Endpoints,
+    manifest_cache::ManifestCache,
 };
 
 #[tokio::main]
@@ -27,6 +29,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             assets_dir: Path::new("assets/"),
         },
         &reqwest::Client::new(),
+        &ManifestCache::new("cache/").retain(2),
+        &Endpoints::default(),
     );
 
     let world_state = WorldState::from_str(&world_state_str, provider)
@@ -0,0 +1,221 @@
+//! Lockfiles for the data bundles downloaded from GitHub.
+//!
+//! The fetcher writes a lockfile next to every downloaded directory (e.g. `data/` ->
+//! `data.lock.json`), recording the repository, the exact commit and a hash of every file. When a
+//! lockfile is present, loading the data verifies the files against it.
+//!
+//! A directory with a lockfile may only hold the files listed in it, besides local state of the
+//! fetcher (dotfiles and interrupted `*.tmp` downloads).
+//!
+//! A lockfile is only written if every file is known to belong to the commit. After a failed
+//! download, or when files were kept because of their age, the fetcher removes the old lockfile
+//! instead.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::context_snapshot::sha256_hex;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleLock {
+    /// `owner/repo`
+    pub repo: String,
+
+    /// The commit the files were downloaded from.
+    pub commit: String,
+
+    /// Hex encoded SHA-256 of every file, keyed by file name.
+    pub files: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LockMismatchError {
+    #[error("{file} does not match the lockfile of {repo}@{commit}")]
+    Changed {
+        repo: String,
+        commit: String,
+        file: String,
+    },
+
+    #[error("{} not listed in the lockfile of {repo}@{commit}", files.join(", "))]
+    Unlisted {
+        repo: String,
+        commit: String,
+        files: Vec<String>,
+    },
+}
+
+/// `data/` -> `data.lock.json`
+///
+/// Paths without a name of their own, like `.` or `..`, are canonicalized first. Fails for the
+/// root directory, as there is no name to derive the lockfile name from.
+pub fn lock_path(dir: &Path) -> io::Result<PathBuf> {
+    let dir = match dir.file_name() {
+        Some(_) => dir.to_owned(),
+        None => dir.canonicalize()?,
+    };
+
+    let Some(name) = dir.file_name() else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "{} has no name to derive a lockfile name from",
+                dir.display()
+            ),
+        ));
+    };
+
+    let mut lock_name = name.to_owned();
+    lock_name.push(".lock.json");
+
+    Ok(dir.with_file_name(lock_name))
+}
+
+/// Files that are local state of the fetcher rather than data, like `.validators.json`.
+pub(crate) fn is_local_state(file_name: &str) -> bool {
+    file_name.starts_with('.') || file_name.ends_with(".tmp")
+}
+
+impl BundleLock {
+    /// Reads the lockfile belonging to `dir`, if there is one.
+    pub fn read(dir: &Path) -> io::Result<Option<Self>> {
+        match fs::read(lock_path(dir)?) {
+            Ok(bytes) => Self::from_slice(&bytes).map(Some),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn from_slice(bytes: &[u8]) -> io::Result<Self> {
+        serde_json::from_slice(bytes).map_err(io::Error::other)
+    }
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        serde_json::to_vec_pretty(self).expect("BundleLock is always serializable")
+    }
+
+    /// Verifies the contents of `file` against the lockfile. Files that aren't part of the
+    /// lockfile are rejected.
+    pub fn verify(&self, file: &str, contents: &[u8]) -> Result<(), LockMismatchError> {
+        match self.files.get(file) {
+            Some(hash) if *hash == sha256_hex(contents) => Ok(()),
+            Some(_) => Err(LockMismatchError::Changed {
+                repo: self.repo.clone(),
+                commit: self.commit.clone(),
+                file: file.to_owned(),
+            }),
+            None => Err(self.unlisted(vec![file.to_owned()])),
+        }
+    }
+
+    /// Verifies that the locked directory holds nothing but the files of the lockfile, given the
+    /// names of all its files.
+    pub fn verify_entries<I, S>(&self, file_names: I) -> Result<(), LockMismatchError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut unlisted: Vec<String> = file_names
+            .into_iter()
+            .map(|file_name| file_name.as_ref().to_owned())
+            .filter(|file_name| !is_local_state(file_name) && !self.files.contains_key(file_name))
+            .collect();
+
+        if unlisted.is_empty() {
+            return Ok(());
+        }
+
+        unlisted.sort();
+
+        Err(self.unlisted(unlisted))
+    }
+
+    fn unlisted(&self, files: Vec<String>) -> LockMismatchError {
+        LockMismatchError::Unlisted {
+            repo: self.repo.clone(),
+            commit: self.commit.clone(),
+            files,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, path::Path};
+
+    use crate::{
+        bundle_lock::{BundleLock, LockMismatchError, lock_path},
+        context_snapshot::sha256_hex,
+    };
+
+    fn lock() -> BundleLock {
+        BundleLock {
+            repo: "WFCD/warframe-worldstate-data".to_owned(),
+            commit: "0123abc".to_owned(),
+            files: BTreeMap::from([("languages.json".to_owned(), sha256_hex(b"{}"))]),
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let lock = lock();
+
+        assert!(lock.verify("languages.json", b"{}").is_ok());
+        assert!(matches!(
+            lock.verify("languages.json", b"[]"),
+            Err(LockMismatchError::Changed { .. })
+        ));
+        assert!(matches!(
+            lock.verify("solNodes.json", b"[]"),
+            Err(LockMismatchError::Unlisted { .. })
+        ));
+    }
+
+    #[test]
+    fn test_verify_entries() {
+        let lock = lock();
+
+        assert!(
+            lock.verify_entries(["languages.json", ".validators.json", "solNodes.json.tmp"])
+                .is_ok()
+        );
+
+        let Err(LockMismatchError::Unlisted { files, .. }) =
+            lock.verify_entries(["solNodes.json", "languages.json", "extra.json"])
+        else {
+            panic!("unlisted files must be rejected");
+        };
+
+        assert_eq!(files, ["extra.json", "solNodes.json"]);
+    }
+
+    #[test]
+    fn test_lock_path() {
+        assert_eq!(
+            lock_path(Path::new("assets/data/")).unwrap(),
+            Path::new("assets/data.lock.json")
+        );
+        assert_eq!(
+            lock_path(Path::new("data/.")).unwrap(),
+            Path::new("data.lock.json")
+        );
+
+        let cwd = std::env::current_dir().unwrap().canonicalize().unwrap();
+
+        assert_eq!(
+            lock_path(Path::new(".")).unwrap(),
+            cwd.with_added_extension("lock.json")
+        );
+        assert_eq!(
+            lock_path(Path::new("..")).unwrap(),
+            cwd.parent().unwrap().with_added_extension("lock.json")
+        );
+        assert!(lock_path(Path::new("/")).is_err());
+    }
+}
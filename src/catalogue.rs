@@ -0,0 +1,617 @@
+//! The optional item catalogue, built from every `PublicExport` manifest.
+//!
+//! Loading it means downloading and parsing another dozen exports, so it's opt-in: see
+//! [`ExportSources::catalogue`](crate::context_sources::ExportSources::catalogue).
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{Context, InternalPath},
+    manifest_entries::manifest_relic_arcane::RelicArcane,
+    manifests::{
+        CatalogueExports,
+        ExportDrones,
+        ExportFlavour,
+        ExportFusionBundles,
+        ExportGear,
+        ExportKeys,
+        ExportRecipes,
+        ExportResources,
+        ExportSentinels,
+        ExportSortieRewards,
+        ExportUpgrades,
+        ExportWarframes,
+        ExportWeapons,
+        Exports,
+    },
+    wfcd_data::language_item::LanguageItemMap,
+};
+
+/// Raw contents of the catalogue exports.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CatalogueSources {
+    /// `ExportWarframes_en.json`
+    pub warframes: Vec<u8>,
+
+    /// `ExportWeapons_en.json`
+    pub weapons: Vec<u8>,
+
+    /// `ExportResources_en.json`
+    pub resources: Vec<u8>,
+
+    /// `ExportUpgrades_en.json`
+    pub upgrades: Vec<u8>,
+
+    /// `ExportRecipes_en.json`
+    pub recipes: Vec<u8>,
+
+    /// `ExportSentinels_en.json`
+    pub sentinels: Vec<u8>,
+
+    /// `ExportKeys_en.json`
+    pub keys: Vec<u8>,
+
+    /// `ExportGear_en.json`
+    pub gear: Vec<u8>,
+
+    /// `ExportDrones_en.json`
+    pub drones: Vec<u8>,
+
+    /// `ExportFlavour_en.json`
+    pub flavour: Vec<u8>,
+
+    /// `ExportFusionBundles_en.json`
+    pub fusion_bundles: Vec<u8>,
+
+    /// `ExportSortieRewards_en.json`
+    pub sortie_rewards: Vec<u8>,
+}
+
+impl CatalogueSources {
+    /// Manifest names of the catalogue exports, in field order.
+    pub const MANIFESTS: [&str; 12] = [
+        "ExportWarframes_en.json",
+        "ExportWeapons_en.json",
+        "ExportResources_en.json",
+        "ExportUpgrades_en.json",
+        "ExportRecipes_en.json",
+        "ExportSentinels_en.json",
+        "ExportKeys_en.json",
+        "ExportGear_en.json",
+        "ExportDrones_en.json",
+        "ExportFlavour_en.json",
+        "ExportFusionBundles_en.json",
+        "ExportSortieRewards_en.json",
+    ];
+
+    /// Builds the sources from the contents of [`CatalogueSources::MANIFESTS`], in that order.
+    pub fn from_manifests(
+        [
+            warframes,
+            weapons,
+            resources,
+            upgrades,
+            recipes,
+            sentinels,
+            keys,
+            gear,
+            drones,
+            flavour,
+            fusion_bundles,
+            sortie_rewards,
+        ]: [Vec<u8>; 12],
+    ) -> Self {
+        Self {
+            warframes,
+            weapons,
+            resources,
+            upgrades,
+            recipes,
+            sentinels,
+            keys,
+            gear,
+            drones,
+            flavour,
+            fusion_bundles,
+            sortie_rewards,
+        }
+    }
+
+    /// Every source, keyed by its manifest name.
+    pub fn manifests(&self) -> [(&'static str, &[u8]); 12] {
+        let [
+            warframes,
+            weapons,
+            resources,
+            upgrades,
+            recipes,
+            sentinels,
+            keys,
+            gear,
+            drones,
+            flavour,
+            fusion_bundles,
+            sortie_rewards,
+        ] = Self::MANIFESTS;
+
+        [
+            (warframes, &self.warframes),
+            (weapons, &self.weapons),
+            (resources, &self.resources),
+            (upgrades, &self.upgrades),
+            (recipes, &self.recipes),
+            (sentinels, &self.sentinels),
+            (keys, &self.keys),
+            (gear, &self.gear),
+            (drones, &self.drones),
+            (flavour, &self.flavour),
+            (fusion_bundles, &self.fusion_bundles),
+            (sortie_rewards, &self.sortie_rewards),
+        ]
+    }
+
+    pub fn parse(&self) -> Result<CatalogueExports, serde_json::Error> {
+        Ok(CatalogueExports {
+            export_warframes: serde_json::from_slice::<ExportWarframes>(&self.warframes)?,
+            export_weapons: serde_json::from_slice::<ExportWeapons>(&self.weapons)?,
+            export_resources: serde_json::from_slice::<ExportResources>(&self.resources)?,
+            export_upgrades: serde_json::from_slice::<ExportUpgrades>(&self.upgrades)?,
+            export_recipes: serde_json::from_slice::<ExportRecipes>(&self.recipes)?,
+            export_sentinels: serde_json::from_slice::<ExportSentinels>(&self.sentinels)?,
+            export_keys: serde_json::from_slice::<ExportKeys>(&self.keys)?,
+            export_gear: serde_json::from_slice::<ExportGear>(&self.gear)?,
+            export_drones: serde_json::from_slice::<ExportDrones>(&self.drones)?,
+            export_flavour: serde_json::from_slice::<ExportFlavour>(&self.flavour)?,
+            export_fusion_bundles: serde_json::from_slice::<ExportFusionBundles>(
+                &self.fusion_bundles,
+            )?,
+            export_sortie_rewards: serde_json::from_slice::<ExportSortieRewards>(
+                &self.sortie_rewards,
+            )?,
+        })
+    }
+}
+
+/// The export an item comes from.
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CatalogueCategory {
+    Warframe,
+    Weapon,
+    Resource,
+    Upgrade,
+    Recipe,
+    Sentinel,
+    Key,
+    Gear,
+    Drone,
+    Flavour,
+    FusionBundle,
+    Relic,
+    Arcane,
+    Customs,
+    /// A sortie reward that none of the other exports has.
+    SortieReward,
+}
+
+/// The fields every catalogue entry shares.
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogueItem {
+    pub unique_name: String,
+
+    pub name: String,
+
+    pub description: Option<String>,
+
+    pub category: CatalogueCategory,
+
+    pub codex_secret: bool,
+}
+
+/// Every export, with a `unique_name` → [`CatalogueItem`] index over all of them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Catalogue {
+    pub exports: CatalogueExports,
+
+    items: HashMap<String, CatalogueItem>,
+}
+
+impl Catalogue {
+    /// Indexes `exports` together with the relics, arcanes and customs of the core `core_exports`.
+    /// Items without a name of their own are named from `language_items`.
+    pub fn new(
+        exports: CatalogueExports,
+        core_exports: &Exports,
+        language_items: &LanguageItemMap,
+    ) -> Self {
+        let language_name = |unique_name: &str| {
+            language_items
+                .get(unique_name)
+                .or_else(|| language_items.get(&unique_name.to_lowercase()))
+                .map(|item| item.value.clone())
+        };
+
+        let mut items = HashMap::new();
+
+        let mut insert = |unique_name: &str,
+                          name: &str,
+                          description: Option<&str>,
+                          category: CatalogueCategory,
+                          codex_secret: bool| {
+            items.insert(
+                unique_name.to_owned(),
+                CatalogueItem {
+                    unique_name: unique_name.to_owned(),
+                    name: name.to_owned(),
+                    description: description
+                        .filter(|description| !description.is_empty())
+                        .map(str::to_owned),
+                    category,
+                    codex_secret,
+                },
+            );
+        };
+
+        for warframe in &exports.export_warframes.export_warframes {
+            insert(
+                &warframe.unique_name,
+                &warframe.name,
+                warframe.description.as_deref(),
+                CatalogueCategory::Warframe,
+                warframe.codex_secret,
+            );
+        }
+
+        for weapon in &exports.export_weapons.export_weapons {
+            insert(
+                &weapon.unique_name,
+                &weapon.name,
+                weapon.description.as_deref(),
+                CatalogueCategory::Weapon,
+                weapon.codex_secret,
+            );
+        }
+
+        for sentinel in &exports.export_sentinels.export_sentinels {
+            insert(
+                &sentinel.unique_name,
+                &sentinel.name,
+                sentinel.description.as_deref(),
+                CatalogueCategory::Sentinel,
+                sentinel.codex_secret,
+            );
+        }
+
+        for upgrade in &exports.export_upgrades.export_upgrades {
+            let description = upgrade.description.join("\n");
+
+            insert(
+                &upgrade.unique_name,
+                &upgrade.name,
+                Some(&description),
+                CatalogueCategory::Upgrade,
+                upgrade.codex_secret,
+            );
+        }
+
+        for drone in &exports.export_drones.export_drones {
+            insert(
+                &drone.unique_name,
+                &drone.name,
+                drone.description.as_deref(),
+                CatalogueCategory::Drone,
+                drone.codex_secret,
+            );
+        }
+
+        for bundle in &exports.export_fusion_bundles.export_fusion_bundles {
+            let name = format!("{} Endo", bundle.fusion_points);
+
+            insert(
+                &bundle.unique_name,
+                &name,
+                bundle.description.as_deref(),
+                CatalogueCategory::FusionBundle,
+                bundle.codex_secret,
+            );
+        }
+
+        for (entries, category) in [
+            (
+                &exports.export_resources.export_resources,
+                CatalogueCategory::Resource,
+            ),
+            (&exports.export_keys.export_keys, CatalogueCategory::Key),
+            (&exports.export_gear.export_gear, CatalogueCategory::Gear),
+            (
+                &exports.export_flavour.export_flavour,
+                CatalogueCategory::Flavour,
+            ),
+        ] {
+            for item in entries {
+                insert(
+                    &item.unique_name,
+                    &item.name,
+                    item.description.as_deref(),
+                    category,
+                    item.codex_secret,
+                );
+            }
+        }
+
+        for relic_arcane in &core_exports.export_relic_arcane.export_relic_arcane {
+            match relic_arcane {
+                RelicArcane::Relic(relic) => insert(
+                    &relic.unique_name,
+                    &relic.name,
+                    Some(&relic.description),
+                    CatalogueCategory::Relic,
+                    relic.codex_secret,
+                ),
+                RelicArcane::Arcane(arcane) => insert(
+                    &arcane.unique_name,
+                    &arcane.name,
+                    None,
+                    CatalogueCategory::Arcane,
+                    arcane.codex_secret,
+                ),
+            }
+        }
+
+        for custom in &core_exports.export_customs.export_customs {
+            insert(
+                &custom.unique_name,
+                &custom.name,
+                custom.description.as_deref(),
+                CatalogueCategory::Customs,
+                custom.codex_secret,
+            );
+        }
+
+        // Recipes usually have no name of their own, they're named after what they craft
+        for recipe in &exports.export_recipes.export_recipes {
+            let result = items.get(&recipe.result_type);
+
+            let Some(name) = language_name(&recipe.unique_name)
+                .or_else(|| result.map(|result| format!("{} Blueprint", result.name)))
+            else {
+                continue;
+            };
+
+            let item = CatalogueItem {
+                unique_name: recipe.unique_name.clone(),
+                name,
+                description: result.and_then(|result| result.description.clone()),
+                category: CatalogueCategory::Recipe,
+                codex_secret: recipe.codex_secret,
+            };
+
+            items.insert(recipe.unique_name.clone(), item);
+        }
+
+        // Sortie rewards are mostly items of the other exports, only the rest is indexed here
+        for reward in &exports.export_sortie_rewards.export_sortie_rewards {
+            let unique_name = reward.reward_name.replacen("/StoreItems", "", 1);
+
+            items.entry(unique_name).or_insert_with_key(|unique_name| {
+                let name = language_name(unique_name)
+                    .or_else(|| language_name(&reward.reward_name))
+                    .unwrap_or_else(|| {
+                        InternalPath::<()>::from(unique_name.clone()).into_title_case_or_path()
+                    });
+
+                CatalogueItem {
+                    unique_name: unique_name.clone(),
+                    name,
+                    description: None,
+                    category: CatalogueCategory::SortieReward,
+                    codex_secret: false,
+                }
+            });
+        }
+
+        Self { exports, items }
+    }
+
+    /// Looks up an item by its unique name. The `/StoreItems` form is accepted as well.
+    pub fn get(&self, unique_name: &str) -> Option<&CatalogueItem> {
+        self.items
+            .get(unique_name)
+            .or_else(|| self.items.get(&unique_name.replacen("/StoreItems", "", 1)))
+    }
+
+    /// Looks up an item by its display name, case insensitively.
+    pub fn find_by_name(&self, name: &str) -> Option<&CatalogueItem> {
+        self.items
+            .values()
+            .find(|item| item.name.eq_ignore_ascii_case(name))
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = &CatalogueItem> {
+        self.items.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+impl Context {
+    /// Looks up an item in the [`Catalogue`]. Always `None` if the catalogue wasn't loaded.
+    pub fn catalogue_item(&self, unique_name: &str) -> Option<&CatalogueItem> {
+        self.catalogue.as_ref()?.get(unique_name)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::{
+        catalogue::{CatalogueCategory, CatalogueSources},
+        context_sources::{ContextSources, tests::fixture_sources},
+    };
+
+    fn list(key: &str, entries: &str) -> Vec<u8> {
+        format!(r#"{{ "{key}": [{entries}] }}"#).into_bytes()
+    }
+
+    /// A catalogue without any items. Tests fill in the exports they need.
+    pub(crate) fn empty_catalogue() -> CatalogueSources {
+        CatalogueSources::from_manifests(
+            CatalogueSources::MANIFESTS
+                .map(|manifest| list(manifest.trim_end_matches("_en.json"), "")),
+        )
+    }
+
+    fn sources() -> ContextSources {
+        let mut catalogue = empty_catalogue();
+        catalogue.weapons = list(
+            "ExportWeapons",
+            r#"{
+                "uniqueName": "/Lotus/Weapons/Tenno/Pistols/PrimeAkstiletto/PrimeAkstilettoPistol",
+                "name": "Akstiletto Prime",
+                "description": "Dual submachine guns.",
+                "codexSecret": false,
+                "masteryReq": 8,
+                "totalDamage": 32
+            }"#,
+        );
+        catalogue.resources = list(
+            "ExportResources",
+            r#"{
+                "uniqueName": "/Lotus/Types/Items/MiscItems/OrokinCell",
+                "name": "Orokin Cell",
+                "description": "",
+                "codexSecret": false
+            }"#,
+        );
+        catalogue.recipes = list(
+            "ExportRecipes",
+            r#"{
+                "uniqueName": "/Lotus/Types/Recipes/Weapons/AkstilettoPrimeBlueprint",
+                "resultType": "/Lotus/Weapons/Tenno/Pistols/PrimeAkstiletto/PrimeAkstilettoPistol",
+                "buildPrice": 25000,
+                "buildTime": 43200,
+                "codexSecret": false,
+                "ingredients": [{
+                    "ItemType": "/Lotus/Types/Items/MiscItems/OrokinCell",
+                    "ItemCount": 1
+                }]
+            },
+            {
+                "uniqueName": "/Lotus/Types/Recipes/Weapons/WeaponParts/AkstilettoPrimeBarrel",
+                "resultType": "/Lotus/Types/Recipes/Weapons/WeaponParts/AkstilettoPrimeBarrelItem",
+                "codexSecret": false
+            }"#,
+        );
+        catalogue.sortie_rewards = list(
+            "ExportSortieRewards",
+            r#"{
+                "rewardName": "/Lotus/StoreItems/Types/Items/MiscItems/OrokinCell",
+                "rarity": "COMMON",
+                "tier": 0,
+                "itemCount": 3,
+                "probability": 0.12
+            },
+            {
+                "rewardName": "/Lotus/StoreItems/Upgrades/Mods/Randomized/LotusPistolRandomModRare",
+                "rarity": "COMMON",
+                "tier": 0,
+                "itemCount": 1,
+                "probability": 0.12
+            },
+            {
+                "rewardName": "/Lotus/StoreItems/Types/Game/KubrowPet/Eggs/KubrowEgg",
+                "rarity": "COMMON",
+                "tier": 0,
+                "itemCount": 1,
+                "probability": 0.12
+            }"#,
+        );
+
+        let mut sources = fixture_sources();
+        sources.worldstate_data.languages = br#"{
+            "/Lotus/Types/Recipes/Weapons/WeaponParts/AkstilettoPrimeBarrel": {
+                "value": "Akstiletto Prime Barrel Blueprint"
+            },
+            "/Lotus/Upgrades/Mods/Randomized/LotusPistolRandomModRare": {
+                "value": "Pistol Riven Mod"
+            }
+        }"#
+        .to_vec();
+        sources.exports.relic_arcane = list(
+            "ExportRelicArcane",
+            r#"{
+                "uniqueName": "/Lotus/Types/Game/Projections/T1VoidProjectionA1Bronze",
+                "name": "Lith A1 Intact",
+                "codexSecret": false,
+                "description": "A relic",
+                "relicRewards": []
+            }"#,
+        );
+        sources.exports.catalogue = Some(catalogue);
+
+        sources
+    }
+
+    #[test]
+    fn test_catalogue() {
+        let ctx = sources().build().unwrap();
+
+        let cell = ctx
+            .catalogue_item("/Lotus/StoreItems/Types/Items/MiscItems/OrokinCell")
+            .unwrap();
+
+        assert_eq!(cell.category, CatalogueCategory::Resource);
+        assert_eq!(cell.description, None);
+
+        let blueprint = ctx
+            .catalogue_item("/Lotus/Types/Recipes/Weapons/AkstilettoPrimeBlueprint")
+            .unwrap();
+
+        assert_eq!(blueprint.name, "Akstiletto Prime Blueprint");
+        assert_eq!(blueprint.category, CatalogueCategory::Recipe);
+
+        // the recipe's own language item comes first, even if its result isn't known
+        let barrel = ctx
+            .catalogue_item("/Lotus/Types/Recipes/Weapons/WeaponParts/AkstilettoPrimeBarrel")
+            .unwrap();
+
+        assert_eq!(barrel.name, "Akstiletto Prime Barrel Blueprint");
+        assert_eq!(barrel.category, CatalogueCategory::Recipe);
+
+        let relic = ctx
+            .catalogue_item("/Lotus/Types/Game/Projections/T1VoidProjectionA1Bronze")
+            .unwrap();
+
+        assert_eq!(relic.category, CatalogueCategory::Relic);
+
+        // sortie rewards keep the category of the export that already has them
+        assert_eq!(cell.name, "Orokin Cell");
+
+        let riven = ctx
+            .catalogue_item("/Lotus/StoreItems/Upgrades/Mods/Randomized/LotusPistolRandomModRare")
+            .unwrap();
+
+        assert_eq!(riven.name, "Pistol Riven Mod");
+        assert_eq!(riven.category, CatalogueCategory::SortieReward);
+
+        let egg = ctx
+            .catalogue_item("/Lotus/StoreItems/Types/Game/KubrowPet/Eggs/KubrowEgg")
+            .unwrap();
+
+        assert_eq!(egg.name, "Kubrow Egg");
+        assert_eq!(egg.category, CatalogueCategory::SortieReward);
+
+        assert!(fixture_sources().build().unwrap().catalogue.is_none());
+    }
+}
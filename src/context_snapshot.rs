@@ -0,0 +1,228 @@
+//! Versioned snapshots of a fully built [`Context`].
+//!
+//! Building a [`Context`] needs a dozen files and some processing. A snapshot stores the result
+//! as a single JSON file, so worker processes can load it instead of rebuilding it.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    BlockingContextProvider,
+    ContextProvider,
+    context_sources::{ContextSources, WorldstateDataError},
+    core::Context,
+};
+
+/// Bumped whenever the layout of [`Context`] changes in an incompatible way.
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 2;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Data(#[from] WorldstateDataError),
+
+    #[error("Unsupported snapshot format version {found} (expected {expected})")]
+    UnsupportedVersion { found: u32, expected: u32 },
+}
+
+/// Hex encoded SHA-256 of `bytes`.
+pub(crate) fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+impl ContextSources {
+    /// SHA-256 hashes of every source, keyed by source name.
+    pub fn hashes(&self) -> BTreeMap<String, String> {
+        let exports = &self.exports;
+        let data = &self.worldstate_data;
+
+        [
+            ("exports.regions", &exports.regions),
+            ("exports.relic_arcane", &exports.relic_arcane),
+            ("exports.customs", &exports.customs),
+            ("exports.crew_battle_nodes", &exports.crew_battle_nodes),
+            ("worldstate_data.languages", &data.languages),
+            ("worldstate_data.sol_nodes", &data.sol_nodes),
+            ("worldstate_data.sortie_data", &data.sortie_data),
+            ("worldstate_data.drops", &data.drops),
+            (
+                "worldstate_data.language_items_ext",
+                &data.language_items_ext,
+            ),
+            (
+                "worldstate_data.archon_hunt_rewards",
+                &data.archon_hunt_rewards,
+            ),
+            (
+                "worldstate_data.archon_shards_store_item",
+                &data.archon_shards_store_item,
+            ),
+        ]
+        .into_iter()
+        .map(|(name, bytes)| (name.to_owned(), sha256_hex(bytes)))
+        .chain(
+            exports
+                .catalogue
+                .iter()
+                .flat_map(|catalogue| catalogue.manifests())
+                .map(|(name, bytes)| (format!("exports.catalogue.{name}"), sha256_hex(bytes))),
+        )
+        .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextSnapshot {
+    /// Always [`SNAPSHOT_FORMAT_VERSION`] for snapshots written by this version of the crate.
+    pub format_version: u32,
+
+    pub created_at: DateTime<Utc>,
+
+    /// Hashes of the data the context was built from, as returned by [`ContextSources::hashes`].
+    /// Empty if the context wasn't built from known sources.
+    pub source_hashes: BTreeMap<String, String>,
+
+    pub context: Context,
+}
+
+impl ContextSnapshot {
+    pub fn new(context: Context, source_hashes: BTreeMap<String, String>) -> Self {
+        Self {
+            format_version: SNAPSHOT_FORMAT_VERSION,
+            created_at: Utc::now(),
+            source_hashes,
+            context,
+        }
+    }
+
+    /// Builds the context from `sources` and records their hashes.
+    pub fn from_sources(sources: &ContextSources) -> Result<Self, SnapshotError> {
+        Ok(Self::new(sources.build()?, sources.hashes()))
+    }
+
+    /// Whether this snapshot was built from exactly these sources.
+    pub fn matches_sources(&self, sources: &ContextSources) -> bool {
+        self.source_hashes == sources.hashes()
+    }
+
+    pub fn to_writer(&self, writer: impl Write) -> Result<(), SnapshotError> {
+        Ok(serde_json::to_writer(writer, self)?)
+    }
+
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Header {
+            format_version: u32,
+        }
+
+        let header: Header = serde_json::from_slice(bytes)?;
+
+        if header.format_version != SNAPSHOT_FORMAT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion {
+                found: header.format_version,
+                expected: SNAPSHOT_FORMAT_VERSION,
+            });
+        }
+
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    pub fn from_reader(mut reader: impl Read) -> Result<Self, SnapshotError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        Self::from_slice(&bytes)
+    }
+
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError> {
+        let mut writer = BufWriter::new(fs::File::create(path)?);
+        self.to_writer(&mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn read_from_file(path: impl AsRef<Path>) -> Result<Self, SnapshotError> {
+        Self::from_reader(BufReader::new(fs::File::open(path)?))
+    }
+
+    pub fn into_context(self) -> Context {
+        self.context
+    }
+}
+
+/// Loads the [`Context`] from a snapshot file written by [`ContextSnapshot::write_to_file`].
+#[derive(Debug, Clone, Copy)]
+pub struct SnapshotContextProvider<'a>(pub &'a Path);
+
+impl BlockingContextProvider for SnapshotContextProvider<'_> {
+    type Err = SnapshotError;
+
+    fn get_ctx_blocking(&self) -> Result<Context, Self::Err> {
+        ContextSnapshot::read_from_file(self.0).map(ContextSnapshot::into_context)
+    }
+}
+
+impl ContextProvider for SnapshotContextProvider<'_> {
+    type Err = SnapshotError;
+
+    async fn get_ctx(&self) -> Result<Context, Self::Err> {
+        self.get_ctx_blocking()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        context_snapshot::{ContextSnapshot, SNAPSHOT_FORMAT_VERSION, SnapshotError},
+        context_sources::tests::fixture_sources,
+    };
+
+    #[test]
+    fn test_roundtrip() {
+        let sources = fixture_sources();
+        let snapshot = ContextSnapshot::from_sources(&sources).unwrap();
+
+        let mut bytes = Vec::new();
+        snapshot.to_writer(&mut bytes).unwrap();
+
+        let loaded = ContextSnapshot::from_slice(&bytes).unwrap();
+
+        assert_eq!(loaded, snapshot);
+        assert!(loaded.matches_sources(&sources));
+    }
+
+    #[test]
+    fn test_version_mismatch() {
+        let snapshot = ContextSnapshot::from_sources(&fixture_sources()).unwrap();
+
+        let mut value = serde_json::to_value(&snapshot).unwrap();
+        value["formatVersion"] = (SNAPSHOT_FORMAT_VERSION + 1).into();
+
+        let result = ContextSnapshot::from_slice(&serde_json::to_vec(&value).unwrap());
+
+        assert!(matches!(
+            result,
+            Err(SnapshotError::UnsupportedVersion { .. })
+        ));
+    }
+}
@@ -0,0 +1,282 @@
+//! The raw sources a [`Context`] is built from.
+//!
+//! Everything here works on byte buffers only, so it's available without any IO features. Loading
+//! the sources from disk lives in `local_context_provider`.
+
+use std::{collections::HashMap, io, sync::LazyLock};
+
+use regex::Regex;
+use serde::de::DeserializeOwned;
+
+#[cfg(feature = "local")]
+use crate::bundle_lock::LockMismatchError;
+use crate::{
+    BlockingContextProvider,
+    ContextProvider,
+    catalogue::{Catalogue, CatalogueSources},
+    core::Context,
+    custom_maps::CustomMaps,
+    endpoints::Endpoints,
+    manifest_entries::manifest_node::ManifestNode,
+    manifests::{ExportCustoms, ExportRegions, ExportRelicArcane, Exports},
+    wfcd_data::{WorldstateData, language_item::LanguageItemMap, relic_drops::Drops},
+};
+
+/// Non-exhaustive, as the `Lock` variant only exists with the `local` feature.
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+#[non_exhaustive]
+pub enum WorldstateDataError {
+    Io(#[from] io::Error),
+    Deserialize(#[from] serde_json::Error),
+    #[cfg(feature = "local")]
+    Lock(#[from] LockMismatchError),
+}
+
+static SOLNODES_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(.*) \(.*\)").unwrap());
+
+/// Raw contents of the export manifests.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ExportSources {
+    /// `ExportRegions_en.json`
+    pub regions: Vec<u8>,
+
+    /// `ExportRelicArcane_en.json`
+    pub relic_arcane: Vec<u8>,
+
+    /// `ExportCustoms_en.json`
+    pub customs: Vec<u8>,
+
+    /// `crewBattleNodes.json` from the assets
+    pub crew_battle_nodes: Vec<u8>,
+
+    /// The exports of the optional [`Catalogue`], not loaded if `None`.
+    pub catalogue: Option<CatalogueSources>,
+}
+
+/// Raw contents of the WFCD data, drops and assets files.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WorldstateDataSources {
+    /// `languages.json` from the WFCD data
+    pub languages: Vec<u8>,
+
+    /// `solNodes.json` from the WFCD data
+    pub sol_nodes: Vec<u8>,
+
+    /// `sortieData.json` from the WFCD data
+    pub sortie_data: Vec<u8>,
+
+    /// `data.json` from the drops
+    pub drops: Vec<u8>,
+
+    /// `languageItemsExt.json` from the assets
+    pub language_items_ext: Vec<u8>,
+
+    /// `archonHuntRewards.json` from the assets
+    pub archon_hunt_rewards: Vec<u8>,
+
+    /// `archonShardsStoreItem.json` from the assets
+    pub archon_shards_store_item: Vec<u8>,
+}
+
+/// Everything needed to build a [`Context`], as raw bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ContextSources {
+    pub exports: ExportSources,
+    pub worldstate_data: WorldstateDataSources,
+}
+
+impl ExportSources {
+    pub fn parse(&self) -> Result<Exports, serde_json::Error> {
+        let mut export_regions: ExportRegions = serde_json::from_slice(&self.regions)?;
+        let crew_battle_nodes: Vec<ManifestNode> = serde_json::from_slice(&self.crew_battle_nodes)?;
+
+        export_regions.export_regions.extend(crew_battle_nodes);
+
+        Ok(Exports {
+            export_regions,
+            export_relic_arcane: serde_json::from_slice::<ExportRelicArcane>(&self.relic_arcane)?,
+            export_customs: serde_json::from_slice::<ExportCustoms>(&self.customs)?,
+        })
+    }
+}
+
+impl WorldstateDataSources {
+    pub fn parse(&self) -> Result<WorldstateData, serde_json::Error> {
+        fn init<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, serde_json::Error> {
+            serde_json::from_slice(bytes)
+        }
+
+        let mut language_items: LanguageItemMap = init(&self.languages)?;
+        let archimedea_ext: LanguageItemMap = init(&self.language_items_ext)?;
+        language_items.extend(archimedea_ext);
+
+        #[derive(serde::Deserialize)]
+        pub struct SolNodeItem {
+            value: String,
+        }
+
+        let sol_nodes: HashMap<String, SolNodeItem> = init(&self.sol_nodes)?;
+        let drops: Drops = init(&self.drops)?;
+
+        let hubs = sol_nodes
+            .into_iter()
+            .filter_map(|(key, value)| {
+                if !key.contains("HUB") {
+                    return None;
+                }
+
+                let relay_name = SOLNODES_REGEX
+                    .captures(&value.value)
+                    .and_then(|cap| cap.get(1))
+                    .map(|r#match| r#match.as_str().to_owned())
+                    .unwrap_or_else(|| value.value);
+
+                Some((key, relay_name))
+            })
+            .collect();
+
+        Ok(WorldstateData {
+            language_items,
+            sortie_data: init(&self.sortie_data)?,
+            unvaulted_relics: drops.unvaulted_relics(),
+            rewards: drops.rewards,
+            hubs,
+            archon_hunt_rewards: init(&self.archon_hunt_rewards)?,
+            archon_shards_store_item: init(&self.archon_shards_store_item)?,
+        })
+    }
+}
+
+impl ContextSources {
+    pub fn build(&self) -> Result<Context, WorldstateDataError> {
+        let exports = self.exports.parse()?;
+        let custom_maps = CustomMaps::new(&exports);
+        let worldstate_data = self.worldstate_data.parse()?;
+
+        let catalogue = match &self.exports.catalogue {
+            Some(sources) => Some(Catalogue::new(
+                sources.parse()?,
+                &exports,
+                &worldstate_data.language_items,
+            )),
+            None => None,
+        };
+
+        Ok(Context {
+            exports,
+            custom_maps,
+            worldstate_data,
+            catalogue,
+            endpoints: Endpoints::default(),
+        })
+    }
+}
+
+/// Builds the [`Context`] from byte buffers that are already in memory.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct InMemoryContextProvider(pub ContextSources);
+
+impl BlockingContextProvider for InMemoryContextProvider {
+    type Err = WorldstateDataError;
+
+    fn get_ctx_blocking(&self) -> Result<Context, Self::Err> {
+        self.0.build()
+    }
+}
+
+impl ContextProvider for InMemoryContextProvider {
+    type Err = WorldstateDataError;
+
+    async fn get_ctx(&self) -> Result<Context, Self::Err> {
+        self.get_ctx_blocking()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::context_sources::{ContextSources, ExportSources, WorldstateDataSources};
+
+    /// The smallest valid set of sources, without any data. Tests fill in what they need.
+    pub(crate) fn fixture_sources() -> ContextSources {
+        ContextSources {
+            exports: ExportSources {
+                regions: br#"{ "ExportRegions": [] }"#.to_vec(),
+                relic_arcane: br#"{ "ExportRelicArcane": [] }"#.to_vec(),
+                customs: br#"{ "ExportCustoms": [] }"#.to_vec(),
+                crew_battle_nodes: b"[]".to_vec(),
+                catalogue: None,
+            },
+            worldstate_data: WorldstateDataSources {
+                languages: b"{}".to_vec(),
+                sol_nodes: b"{}".to_vec(),
+                sortie_data: br#"{
+                    "modifierTypes": {},
+                    "modifierDescriptions": {},
+                    "bosses": {},
+                    "modifiers": []
+                }"#
+                .to_vec(),
+                drops: br#"{
+                    "cetusBountyRewards": [],
+                    "solarisBountyRewards": [],
+                    "deimosRewards": [],
+                    "zarimanRewards": [],
+                    "entratiLabRewards": [],
+                    "hexRewards": [],
+                    "sortieRewards": []
+                }"#
+                .to_vec(),
+                language_items_ext: b"{}".to_vec(),
+                archon_hunt_rewards: b"[]".to_vec(),
+                archon_shards_store_item: b"{}".to_vec(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_build_from_memory() {
+        let mut sources = fixture_sources();
+        sources.exports.regions = br#"{ "ExportRegions": [{
+            "uniqueName": "SolNode1",
+            "name": "Galatea",
+            "systemIndex": 5,
+            "systemName": "Neptune",
+            "nodeType": 0,
+            "masteryReq": 0,
+            "missionIndex": 5,
+            "factionIndex": 1,
+            "minEnemyLevel": 27,
+            "maxEnemyLevel": 29
+        }] }"#
+            .to_vec();
+        sources.exports.relic_arcane = br#"{ "ExportRelicArcane": [{
+            "uniqueName": "/Lotus/Types/Game/Projections/T1VoidProjectionA1Bronze",
+            "name": "Lith A1 Intact",
+            "codexSecret": false,
+            "description": "A relic",
+            "relicRewards": []
+        }] }"#
+            .to_vec();
+        sources.worldstate_data.sol_nodes =
+            br#"{ "MercuryHUB": { "value": "Larunda Relay (Mercury)" } }"#.to_vec();
+
+        let ctx = sources.build().unwrap();
+
+        assert_eq!(
+            ctx.worldstate_data
+                .hubs
+                .get("MercuryHUB")
+                .map(String::as_str),
+            Some("Larunda Relay")
+        );
+        assert_eq!(
+            ctx.custom_maps
+                .solnode_to_region
+                .get("SolNode1")
+                .map(|node| node.name.as_str()),
+            Some("Galatea")
+        );
+        assert_eq!(ctx.custom_maps.relic_uniq_to_relic.len(), 1);
+    }
+}
@@ -1,31 +1,138 @@
 use heck::ToTitleCase;
 
-use crate::core::{
-    ContextRef,
-    InternalPath,
-    Resolve,
-    resolve_with::{self, LanguageItems},
+use crate::{
+    core::{
+        ContextRef,
+        InternalPath,
+        Resolve,
+        resolve_with::{self, LanguageItems},
+    },
+    target_types::worldstate_types::calendar::CalendarReward,
 };
 
 impl Resolve<ContextRef<'_>> for InternalPath<resolve_with::CalendarReward> {
-    type Output = String;
+    type Output = CalendarReward;
 
     fn resolve(self, ctx: ContextRef<'_>) -> Self::Output {
-        match &self.path {
-            path if path.contains("ArchonCrystal") => ctx
-                .worldstate_data
+        if let Some(arcane) = ctx.arcane(&self.path) {
+            return CalendarReward {
+                name: arcane.name.clone(),
+                arcane: Some(arcane.unique_name.clone()),
+            };
+        }
+
+        let name = if let Some(name) = self.clone().cast::<resolve_with::StoreItem>().resolve(ctx) {
+            name
+        } else if self.path.contains("ArchonCrystal") {
+            ctx.worldstate_data
                 .archon_shards_store_item
-                .get(path)
+                .get(&self.path)
                 .cloned()
-                .unwrap_or_else(|| self.into_title_case_or_path()),
+                .unwrap_or_else(|| self.into_title_case_or_path())
+        } else {
+            // Language items are keyed by the real unique name, e.g. the arcane adapters as
+            // `/Lotus/Types/Items/MiscItems/WeaponPrimaryArcaneUnlocker`
+            let unique_name = self.path.replacen("/StoreItems", "", 1);
+            let items = &ctx.worldstate_data.language_items;
 
-            path if path.contains("ArcaneUnlocker") => self
-                .last_segment()
-                .and_then(|s| s.strip_prefix("Weapon"))
-                .map(|s| s.replace("Unlocker", "Adapter").to_title_case())
-                .unwrap_or_else(|| self.into_title_case_or_path()),
+            if let Some(item) = items.get(&self.path).or_else(|| items.get(&unique_name)) {
+                item.value.clone()
+            } else if self.path.contains("ArcaneUnlocker") {
+                self.last_segment()
+                    .and_then(|s| s.strip_prefix("Weapon"))
+                    .map(|s| s.replace("Unlocker", "Adapter").to_title_case())
+                    .unwrap_or_else(|| self.into_title_case_or_path())
+            } else {
+                InternalPath::<LanguageItems>::from(unique_name).resolve(ctx)
+            }
+        };
 
-            _ => InternalPath::<LanguageItems>::resolve(self.cast(), ctx),
-        }
+        CalendarReward { name, arcane: None }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        context_sources::{ContextSources, tests::fixture_sources},
+        core::{InternalPath, Resolve, resolve_with},
+        target_types::{arcane::ArcaneRarity, worldstate_types::calendar::CalendarReward},
+    };
+
+    fn sources() -> ContextSources {
+        let mut sources = fixture_sources();
+        sources.exports.relic_arcane = br#"{ "ExportRelicArcane": [{
+            "uniqueName": "/Lotus/Upgrades/CosmeticEnhancers/Offensive/CritChanceOnHeadshot",
+            "name": "Arcane Avenger",
+            "codexSecret": false,
+            "rarity": "UNCOMMON",
+            "levelStats": [
+                { "stats": ["+3% Critical Chance"] },
+                { "stats": ["+6% Critical Chance"] }
+            ]
+        }] }"#
+            .to_vec();
+        sources
+    }
+
+    #[test]
+    fn test_arcane() {
+        let ctx = sources().build().unwrap();
+
+        let arcane = ctx
+            .arcane("/Lotus/StoreItems/Upgrades/CosmeticEnhancers/Offensive/CritChanceOnHeadshot")
+            .unwrap();
+
+        assert_eq!(arcane.rarity, ArcaneRarity::Uncommon);
+        assert_eq!(arcane.max_rank(), Some(1));
+        assert_eq!(
+            arcane.stats_at(1),
+            Some(["+6% Critical Chance".to_owned()].as_slice())
+        );
+        assert_eq!(ctx.arcane_by_name("arcane avenger"), Some(arcane));
+    }
+
+    #[test]
+    fn test_resolve() {
+        let mut sources = sources();
+        sources.worldstate_data.language_items_ext = br#"{
+            "/Lotus/Types/Items/MiscItems/WeaponMeleeArcaneUnlocker": {
+                "value": "Melee Arcane Adapter (Localized)"
+            }
+        }"#
+        .to_vec();
+
+        let ctx = sources.build().unwrap();
+
+        let resolve = |path: &str| {
+            InternalPath::<resolve_with::CalendarReward>::from(path.to_owned())
+                .resolve(ctx.as_ref())
+        };
+
+        assert_eq!(
+            resolve("/Lotus/StoreItems/Upgrades/CosmeticEnhancers/Offensive/CritChanceOnHeadshot"),
+            CalendarReward {
+                name: "Arcane Avenger".to_owned(),
+                arcane: Some(
+                    "/Lotus/Upgrades/CosmeticEnhancers/Offensive/CritChanceOnHeadshot".to_owned()
+                ),
+            }
+        );
+        // the language item wins over the adapter mapping
+        assert_eq!(
+            resolve("/Lotus/StoreItems/Types/Items/MiscItems/WeaponMeleeArcaneUnlocker"),
+            CalendarReward {
+                name: "Melee Arcane Adapter (Localized)".to_owned(),
+                arcane: None,
+            }
+        );
+        // without a language item, the adapter name is derived from the path
+        assert_eq!(
+            resolve("/Lotus/StoreItems/Types/Items/MiscItems/WeaponSecondaryArcaneUnlocker"),
+            CalendarReward {
+                name: "Secondary Arcane Adapter".to_owned(),
+                arcane: None,
+            }
+        );
     }
 }
@@ -1,6 +1,7 @@
 mod calendar_reward_resolve;
 pub mod resolvable_string;
 pub(crate) mod sol_node;
+mod store_item_resolve;
 pub mod vault_trader_resolve;
 
 use std::marker::PhantomData;
@@ -9,17 +10,27 @@ use heck::ToTitleCase;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    catalogue::Catalogue,
     custom_maps::CustomMaps,
+    endpoints::Endpoints,
     manifests::Exports,
-    target_types::display_info::DisplayInfo,
+    target_types::{arcane::Arcane, display_info::DisplayInfo},
     wfcd_data::WorldstateData,
 };
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Context {
     pub exports: Exports,
     pub custom_maps: CustomMaps,
     pub worldstate_data: WorldstateData,
+
+    /// Only present if the catalogue exports were loaded.
+    #[serde(default)]
+    pub catalogue: Option<Catalogue>,
+
+    /// Where the links in the parsed worldstate point to.
+    #[serde(default)]
+    pub endpoints: Endpoints,
 }
 
 impl Context {
@@ -28,8 +39,23 @@ impl Context {
             exports: &self.exports,
             custom_maps: &self.custom_maps,
             worldstate_data: &self.worldstate_data,
+            catalogue: self.catalogue.as_ref(),
+            endpoints: &self.endpoints,
         }
     }
+
+    /// Looks up an arcane by its unique name. The `/StoreItems` form is accepted as well.
+    pub fn arcane(&self, unique_name: &str) -> Option<&Arcane> {
+        self.as_ref().arcane(unique_name)
+    }
+
+    /// Looks up an arcane by its display name (e.g. a calendar reward), case insensitively.
+    pub fn arcane_by_name(&self, name: &str) -> Option<&Arcane> {
+        self.custom_maps
+            .arcane_uniq_to_arcane
+            .values()
+            .find(|arcane| arcane.name.eq_ignore_ascii_case(name))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -37,6 +63,19 @@ pub struct ContextRef<'a> {
     pub exports: &'a Exports,
     pub custom_maps: &'a CustomMaps,
     pub worldstate_data: &'a WorldstateData,
+    pub catalogue: Option<&'a Catalogue>,
+    pub endpoints: &'a Endpoints,
+}
+
+impl<'a> ContextRef<'a> {
+    /// See [`Context::arcane`].
+    pub fn arcane(self, unique_name: &str) -> Option<&'a Arcane> {
+        let arcanes = &self.custom_maps.arcane_uniq_to_arcane;
+
+        arcanes
+            .get(unique_name)
+            .or_else(|| arcanes.get(&unique_name.replacen("/StoreItems", "", 1)))
+    }
 }
 
 pub trait Resolve<Ctx> {
@@ -103,6 +142,7 @@ pub mod resolve_with {
         PrimePart;
         TitleCase;
         CalendarReward;
+        StoreItem;
         sortie {
             Modifier;
             Boss;
@@ -116,7 +156,7 @@ pub mod resolve_with {
 #[derive(
     derive_more::Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash, derive_more::Display,
 )]
-#[serde(from = "String")]
+#[serde(from = "String", into = "String", bound(serialize = "Resolver: Clone"))]
 #[display("{path}")]
 pub struct InternalPath<Resolver = ()> {
     pub path: String,
@@ -135,6 +175,12 @@ impl<Resolver> From<String> for InternalPath<Resolver> {
     }
 }
 
+impl<Resolver> From<InternalPath<Resolver>> for String {
+    fn from(path: InternalPath<Resolver>) -> Self {
+        path.path
+    }
+}
+
 impl<T> InternalPath<T> {
     pub fn new<S>(s: String) -> InternalPath<S> {
         InternalPath {
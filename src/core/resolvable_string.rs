@@ -5,7 +5,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     core::{ContextRef, Resolve, resolve_with},
-    wfcd_data::sortie_data::Boss,
+    target_types::worldstate_types::{
+        archon_hunt::Archon,
+        sortie::{SortieBoss, SortieModifier},
+    },
 };
 
 #[derive(derive_more::Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
@@ -19,23 +22,33 @@ impl<T> From<String> for ResolvableString<T> {
 }
 
 impl Resolve<ContextRef<'_>> for ResolvableString<resolve_with::sortie::Modifier> {
-    type Output = String;
+    type Output = SortieModifier;
 
     fn resolve(self, ctx: ContextRef<'_>) -> Self::Output {
-        ctx.worldstate_data
-            .sortie_data
-            .modifier_types
-            .get(&self.0)
-            .cloned()
-            .unwrap_or_else(|| self.0.to_title_case())
+        let sortie_data = &ctx.worldstate_data.sortie_data;
+
+        SortieModifier {
+            name: sortie_data
+                .modifier_types
+                .get(&self.0)
+                .cloned()
+                .unwrap_or_else(|| self.0.to_title_case()),
+            description: sortie_data.modifier_descriptions.get(&self.0).cloned(),
+        }
     }
 }
 
-impl<'a> Resolve<ContextRef<'a>> for ResolvableString<resolve_with::sortie::Boss> {
-    type Output = Option<&'a Boss>;
+impl Resolve<ContextRef<'_>> for ResolvableString<resolve_with::sortie::Boss> {
+    type Output = Option<SortieBoss>;
+
+    fn resolve(self, ctx: ContextRef<'_>) -> Self::Output {
+        let boss = ctx.worldstate_data.sortie_data.bosses.get(&self.0)?;
 
-    fn resolve(self, ctx: ContextRef<'a>) -> Self::Output {
-        ctx.worldstate_data.sortie_data.bosses.get(&self.0)
+        Some(SortieBoss {
+            name: boss.name.clone(),
+            faction: boss.faction.resolve(()),
+            shard_color: Archon::from_boss_key(&self.0).map(Archon::shard_color),
+        })
     }
 }
 
@@ -0,0 +1,70 @@
+use crate::core::{ContextRef, InternalPath, Resolve, resolve_with};
+
+/// Looks the item up in the [`Catalogue`](crate::catalogue::Catalogue). Both `/StoreItems/` paths
+/// and real unique names are accepted.
+///
+/// `None` if the catalogue isn't loaded or doesn't know the item, so callers can fall back to
+/// guessing the name from the path.
+impl Resolve<ContextRef<'_>> for InternalPath<resolve_with::StoreItem> {
+    type Output = Option<String>;
+
+    fn resolve(self, ctx: ContextRef<'_>) -> Self::Output {
+        ctx.catalogue?.get(&self.path).map(|item| item.name.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        catalogue::tests::empty_catalogue,
+        context_sources::tests::fixture_sources,
+        core::{InternalPath, Resolve, resolve_with},
+    };
+
+    #[test]
+    fn test_catalogue_first() {
+        let path = "/Lotus/StoreItems/Weapons/Tenno/Pistols/PrimeAkstiletto/PrimeAkstilettoPistol";
+
+        let mut catalogue = empty_catalogue();
+        catalogue.weapons = br#"{ "ExportWeapons": [{
+            "uniqueName": "/Lotus/Weapons/Tenno/Pistols/PrimeAkstiletto/PrimeAkstilettoPistol",
+            "name": "Akstiletto Prime",
+            "description": "Dual submachine guns.",
+            "codexSecret": false
+        }] }"#
+            .to_vec();
+        catalogue.recipes = br#"{ "ExportRecipes": [{
+            "uniqueName": "/Lotus/Types/Recipes/Weapons/AkstilettoPrimeBlueprint",
+            "resultType": "/Lotus/Weapons/Tenno/Pistols/PrimeAkstiletto/PrimeAkstilettoPistol",
+            "buildPrice": 25000,
+            "buildTime": 43200,
+            "codexSecret": false,
+            "ingredients": []
+        }] }"#
+            .to_vec();
+
+        let mut sources = fixture_sources();
+        sources.exports.catalogue = Some(catalogue);
+
+        let ctx = sources.build().unwrap();
+        let item: InternalPath<resolve_with::VaultTraderItem> = path.to_owned().into();
+
+        assert_eq!(item.resolve(ctx.as_ref()), "Akstiletto Prime");
+
+        let reward: InternalPath<resolve_with::CalendarReward> =
+            "/Lotus/StoreItems/Types/Recipes/Weapons/AkstilettoPrimeBlueprint"
+                .to_owned()
+                .into();
+
+        assert_eq!(
+            reward.resolve(ctx.as_ref()).name,
+            "Akstiletto Prime Blueprint"
+        );
+
+        // Without the catalogue, the name is guessed from the path
+        let ctx = fixture_sources().build().unwrap();
+        let item: InternalPath<resolve_with::VaultTraderItem> = path.to_owned().into();
+
+        assert_eq!(item.resolve(ctx.as_ref()), "Akstiletto Pistol Prime");
+    }
+}
@@ -18,6 +18,11 @@ impl Resolve<ContextRef<'_>> for InternalPath<resolve_with::VaultTraderItem> {
     type Output = String;
 
     fn resolve(self, ctx: ContextRef<'_>) -> Self::Output {
+        if let Some(name) = self.clone().cast::<resolve_with::StoreItem>().resolve(ctx) {
+            return name;
+        }
+
+        // The catalogue isn't loaded or doesn't know the item, guess the name from the path
         match &self.path {
             path if path.contains("Weapons") => prime_part(self.cast()),
             path if path.contains("Powersuits") => {
@@ -1,18 +1,21 @@
 use std::collections::HashMap;
 
+use serde::{Deserialize, Serialize};
+
 use crate::{
     core::Resolve,
     manifest_entries::{customs_entry::CustomsEntry, manifest_relic_arcane::RelicArcane},
     manifests::Exports,
-    target_types::{node::Node, relic::Relic},
+    target_types::{arcane::Arcane, node::Node, relic::Relic},
 };
 
 pub type SolNodeToRegionMap = HashMap<String, Node>;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CustomMaps {
     pub solnode_to_region: SolNodeToRegionMap,
     pub relic_uniq_to_relic: HashMap<String, Relic>,
+    pub arcane_uniq_to_arcane: HashMap<String, Arcane>,
     pub unique_to_customs_entry: HashMap<String, CustomsEntry>,
 }
 
@@ -32,7 +35,7 @@ impl CustomMaps {
             .iter()
             .cloned()
             .filter_map(|relic_arcane| match relic_arcane {
-                RelicArcane::Arcane(_a) => None,
+                RelicArcane::Arcane(_) => None,
                 RelicArcane::Relic(manifest_relic) => Some((
                     manifest_relic.unique_name.clone(),
                     manifest_relic.resolve(()),
@@ -40,6 +43,19 @@ impl CustomMaps {
             })
             .collect();
 
+        let arcane_map = exports
+            .export_relic_arcane
+            .export_relic_arcane
+            .iter()
+            .cloned()
+            .filter_map(|relic_arcane| match relic_arcane {
+                RelicArcane::Arcane(arcane) => {
+                    Some((arcane.unique_name.clone(), arcane.resolve(())))
+                },
+                RelicArcane::Relic(_) => None,
+            })
+            .collect();
+
         let customs_map = exports
             .export_customs
             .export_customs
@@ -51,6 +67,7 @@ impl CustomMaps {
         Self {
             solnode_to_region: region,
             relic_uniq_to_relic: relic_map,
+            arcane_uniq_to_arcane: arcane_map,
             unique_to_customs_entry: customs_map,
         }
     }
@@ -1,47 +1,51 @@
-use std::{collections::HashMap, io, path::Path, string::FromUtf8Error, sync::LazyLock};
+use std::{io, path::Path, string::FromUtf8Error};
 
-use regex::Regex;
-use reqwest::get;
 use serde::de::DeserializeOwned;
 use tokio::fs;
-use tracing::debug;
 
+pub use crate::local_context_provider::{PathContext, WorldstateDataError};
 use crate::{
     ContextProvider,
+    bundle_lock::{BundleLock, lock_path},
+    catalogue::{Catalogue, CatalogueSources},
     core::Context,
     custom_maps::CustomMaps,
+    endpoints::Endpoints,
+    local_context_provider::WorldstateDataSources,
+    manifest_cache::ManifestCache,
     manifest_entries::manifest_node::ManifestNode,
-    manifests::{self, ExportRegions, Exports, MissingManifestKeyError},
-    wfcd_data::{WorldstateData, language_item::LanguageItemMap},
+    manifests::{ExportRegions, Exports, MissingManifestKeyError, PublicExportIndex},
+    wfcd_data::WorldstateData,
 };
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct PathContext<'a> {
-    pub data_dir: &'a Path,
-    pub drops_dir: &'a Path,
-    pub assets_dir: &'a Path,
+async fn read_json(dir: &Path, file: &str) -> io::Result<Vec<u8>> {
+    fs::read(dir.join(file).with_extension("json")).await
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error(transparent)]
-pub enum WorldstateDataError {
-    Io(#[from] io::Error),
-    Deserialize(#[from] serde_json::Error),
+async fn read_lock(dir: &Path) -> io::Result<Option<BundleLock>> {
+    match fs::read(lock_path(dir)?).await {
+        Ok(bytes) => BundleLock::from_slice(&bytes).map(Some),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
 }
 
-const CACHE_DIR: &str = "./cache";
+/// Verifies that `dir` holds nothing but the files of its lockfile, if it has one.
+async fn verify_entries(dir: &Path, lock: Option<&BundleLock>) -> Result<(), WorldstateDataError> {
+    let Some(lock) = lock else {
+        return Ok(());
+    };
 
-static SOLNODES_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(.*) \(.*\)").unwrap());
+    let mut file_names = Vec::new();
+    let mut entries = fs::read_dir(dir).await?;
 
-async fn init<T: DeserializeOwned>(
-    data_dir: impl AsRef<Path>,
-    file: impl AsRef<Path>,
-) -> Result<T, WorldstateDataError> {
-    let path = data_dir.as_ref().join(file.as_ref().with_extension("json"));
+    while let Some(entry) = entries.next_entry().await? {
+        if entry.file_type().await?.is_file() {
+            file_names.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
 
-    Ok(serde_json::from_str(
-        fs::read_to_string(path).await?.as_str(),
-    )?)
+    Ok(lock.verify_entries(file_names)?)
 }
 
 async fn create_worldstate_data(
@@ -51,63 +55,76 @@ async fn create_worldstate_data(
         drops_dir,
     }: PathContext<'_>,
 ) -> Result<WorldstateData, WorldstateDataError> {
-    let mut language_items: LanguageItemMap = init(data_dir, "languages").await?;
-    let archimedea_ext: LanguageItemMap = init(assets_dir, "languageItemsExt").await?;
-    language_items.extend(archimedea_ext);
-
-    #[derive(serde::Deserialize)]
-    pub struct SolNodeItem {
-        value: String,
-    }
-
-    let sol_nodes: HashMap<String, SolNodeItem> = init(data_dir, "solNodes").await?;
+    let sources = WorldstateDataSources {
+        languages: read_json(data_dir, "languages").await?,
+        sol_nodes: read_json(data_dir, "solNodes").await?,
+        sortie_data: read_json(data_dir, "sortieData").await?,
+        drops: read_json(drops_dir, "data").await?,
+        language_items_ext: read_json(assets_dir, "languageItemsExt").await?,
+        archon_hunt_rewards: read_json(assets_dir, "archonHuntRewards").await?,
+        archon_shards_store_item: read_json(assets_dir, "archonShardsStoreItem").await?,
+    };
 
-    let hubs = sol_nodes
-        .into_iter()
-        .filter_map(|(key, value)| {
-            if !key.contains("HUB") {
-                return None;
-            }
+    let data_lock = read_lock(data_dir).await?;
+    let assets_lock = read_lock(assets_dir).await?;
 
-            let relay_name = SOLNODES_REGEX
-                .captures(&value.value)
-                .and_then(|cap| cap.get(1))
-                .map(|r#match| r#match.as_str().to_owned())
-                .unwrap_or_else(|| value.value);
+    sources.verify(data_lock.as_ref(), assets_lock.as_ref())?;
+    verify_entries(data_dir, data_lock.as_ref()).await?;
+    verify_entries(assets_dir, assets_lock.as_ref()).await?;
 
-            Some((key, relay_name))
-        })
-        .collect();
-
-    Ok(WorldstateData {
-        language_items,
-        sortie_data: init(data_dir, "sortieData").await?,
-        rewards: init(drops_dir, "data").await?,
-        hubs,
-        archon_hunt_rewards: init(assets_dir, "archonHuntRewards").await?,
-        archon_shards_store_item: init(assets_dir, "archonShardsStoreItem").await?,
-    })
+    Ok(sources.parse()?)
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct DefaultContextProvider<'a>(pub PathContext<'a>, pub &'a reqwest::Client);
-
-impl ContextProvider for DefaultContextProvider<'_> {
-    type Err = DefaultContextProviderError;
+pub struct DefaultContextProvider<'a>(
+    pub PathContext<'a>,
+    pub &'a reqwest::Client,
+    pub &'a ManifestCache,
+    pub &'a Endpoints,
+);
+
+impl DefaultContextProvider<'_> {
+    /// Like [`ContextProvider::get_ctx`], but also downloads the catalogue exports and builds the
+    /// [`Catalogue`].
+    pub async fn get_ctx_with_catalogue(&self) -> Result<Context, DefaultContextProviderError> {
+        self.build(true).await
+    }
 
-    async fn get_ctx(&self) -> Result<Context, Self::Err> {
-        let exports = get_export(&self.0.assets_dir.join("crewBattleNodes.json"), self.1).await?;
+    async fn build(&self, with_catalogue: bool) -> Result<Context, DefaultContextProviderError> {
+        let (exports, index) = get_export(self.0.assets_dir, self.1, self.2, self.3).await?;
         let custom_maps = CustomMaps::new(&exports);
         let worldstate_data = create_worldstate_data(self.0).await?;
 
+        let catalogue = if with_catalogue {
+            let sources = get_catalogue_sources(&index, self.1, self.2, self.3).await?;
+
+            Some(Catalogue::new(
+                sources.parse()?,
+                &exports,
+                &worldstate_data.language_items,
+            ))
+        } else {
+            None
+        };
+
         Ok(Context {
             custom_maps,
             exports,
             worldstate_data,
+            catalogue,
+            endpoints: self.3.clone(),
         })
     }
 }
 
+impl ContextProvider for DefaultContextProvider<'_> {
+    type Err = DefaultContextProviderError;
+
+    async fn get_ctx(&self) -> Result<Context, Self::Err> {
+        self.build(false).await
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
 pub enum DefaultContextProviderError {
@@ -119,79 +136,127 @@ pub enum DefaultContextProviderError {
     DataError(#[from] WorldstateDataError),
 }
 
+async fn fetch_manifest(
+    manifest: &str,
+    client: &reqwest::Client,
+    cache: &ManifestCache,
+    endpoints: &Endpoints,
+) -> Result<String, DefaultContextProviderError> {
+    if let Some(cached) = cache.read(manifest).await {
+        return Ok(cached);
+    }
+
+    let item_json = client
+        .get(endpoints.manifest_url(manifest))
+        .send()
+        .await?
+        .text()
+        .await?;
+
+    cache.write(manifest, &item_json).await?;
+
+    Ok(item_json)
+}
+
 async fn get_from_cache_or_fetch<T: DeserializeOwned>(
     manifest: &str,
+    client: &reqwest::Client,
+    cache: &ManifestCache,
+    endpoints: &Endpoints,
 ) -> Result<T, DefaultContextProviderError> {
-    fs::create_dir_all(CACHE_DIR).await?;
-
-    let path = Path::new(CACHE_DIR)
-        .join(manifest)
-        .with_added_extension("json");
+    Ok(serde_json::from_str(
+        &fetch_manifest(manifest, client, cache, endpoints).await?,
+    )?)
+}
 
-    if let Ok(cached) = fs::read_to_string(&path).await {
-        debug!("Using cache at {}", path.to_str().unwrap());
-        return Ok(serde_json::from_str(&cached)?);
+async fn get_catalogue_sources(
+    index: &PublicExportIndex,
+    client: &reqwest::Client,
+    cache: &ManifestCache,
+    endpoints: &Endpoints,
+) -> Result<CatalogueSources, DefaultContextProviderError> {
+    // Same order as `CatalogueSources::MANIFESTS`
+    let manifests = [
+        &index.warframes,
+        &index.weapons,
+        &index.resources,
+        &index.upgrades,
+        &index.recipes,
+        &index.sentinels,
+        &index.keys,
+        &index.gear,
+        &index.drones,
+        &index.flavour,
+        &index.fusion_bundles,
+        &index.sortie_rewards,
+    ];
+
+    let mut contents = Vec::with_capacity(manifests.len());
+
+    for manifest in manifests {
+        contents.push(
+            fetch_manifest(manifest, client, cache, endpoints)
+                .await?
+                .into_bytes(),
+        );
     }
 
-    let item_json = get(format!(
-        "http://content.warframe.com/PublicExport/Manifest/{manifest}"
+    Ok(CatalogueSources::from_manifests(
+        contents.try_into().expect("one entry per manifest"),
     ))
-    .await?
-    .text()
-    .await?;
-
-    let mut files = fs::read_dir(CACHE_DIR).await?;
-
-    while let Ok(Some(file)) = files.next_entry().await {
-        let file_name = file.file_name().into_string().unwrap();
-
-        if file_name.starts_with(
-            manifest
-                .split_once('!')
-                .expect("Manifest should be valid")
-                .0,
-        ) {
-            fs::remove_file(file_name).await?;
-        }
-    }
-
-    fs::write(path, &item_json).await?;
-
-    Ok(serde_json::from_str(&item_json)?)
 }
 
+/// Builds the exports. `crewBattleNodes.json` is read from `assets_dir` and verified against its
+/// lockfile, if any.
 async fn get_export(
-    ctx: &Path,
+    assets_dir: &Path,
     client: &reqwest::Client,
-) -> Result<Exports, DefaultContextProviderError> {
+    cache: &ManifestCache,
+    endpoints: &Endpoints,
+) -> Result<(Exports, PublicExportIndex), DefaultContextProviderError> {
     let file = client
-        .get("https://origin.warframe.com/PublicExport/index_en.txt.lzma")
+        .get(&endpoints.export_index)
         .send()
         .await?
         .bytes()
         .await?
         .to_vec();
 
+    cache.write_index(&file).await?;
+
     let mut buffer: Vec<u8> = Vec::new();
 
     lzma_rs::lzma_decompress(&mut file.as_slice(), &mut buffer).unwrap();
 
     let data = String::from_utf8(buffer)?;
 
-    let export: manifests::PublicExportIndex = data.parse()?;
+    let export: PublicExportIndex = data.parse()?;
+
+    let crew_battle_nodes = read_json(assets_dir, "crewBattleNodes").await?;
+
+    if let Some(lock) = read_lock(assets_dir).await? {
+        lock.verify("crewBattleNodes.json", &crew_battle_nodes)
+            .map_err(WorldstateDataError::from)?;
+    }
 
-    let crew_battle_nodes_json: Vec<ManifestNode> =
-        serde_json::from_str(&fs::read_to_string(ctx).await?)?;
+    let crew_battle_nodes_json: Vec<ManifestNode> = serde_json::from_slice(&crew_battle_nodes)?;
 
-    let mut export_regions: ExportRegions = get_from_cache_or_fetch(&export.regions).await?;
+    let mut export_regions: ExportRegions =
+        get_from_cache_or_fetch(&export.regions, client, cache, endpoints).await?;
 
     export_regions.export_regions.extend(crew_battle_nodes_json);
 
     let exports = Exports {
         export_regions,
-        export_relic_arcane: get_from_cache_or_fetch(&export.relic_arcane).await?,
-        export_customs: get_from_cache_or_fetch(&export.customs).await?,
+        export_relic_arcane: get_from_cache_or_fetch(
+            &export.relic_arcane,
+            client,
+            cache,
+            endpoints,
+        )
+        .await?,
+        export_customs: get_from_cache_or_fetch(&export.customs, client, cache, endpoints).await?,
     };
 
-    Ok(exports)
+    Ok((exports, export))
 }
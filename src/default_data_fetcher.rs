@@ -1,13 +1,28 @@
 use std::{
+    collections::{BTreeMap, HashMap},
     path::{Path, PathBuf},
+    sync::Arc,
     time::{Duration, SystemTime},
 };
 
-use reqwest::Client;
-use serde::Deserialize;
-use tokio::fs;
+use reqwest::{
+    Client,
+    RequestBuilder,
+    Response,
+    StatusCode,
+    header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+};
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use tokio::{fs, task::JoinSet};
 use tracing::{debug, error, info};
 
+use crate::{
+    bundle_lock::{BundleLock, lock_path},
+    context_snapshot::sha256_hex,
+    endpoints::Endpoints,
+};
+
 #[derive(Debug, thiserror::Error)]
 pub enum FetchError {
     #[error("Request failed: {0}")]
@@ -24,6 +39,80 @@ pub enum FetchError {
 
     #[error("Download failed for {path}: {message}")]
     Download { path: String, message: String },
+
+    #[error("Required files are missing: {}", missing.join(", "))]
+    MissingRequired {
+        missing: Vec<String>,
+        report: FetchReport,
+    },
+
+    /// Required files failed to download. Older copies may still exist on disk.
+    #[error("Required files failed to download: {}", failed.join(", "))]
+    FailedRequired {
+        failed: Vec<String>,
+        report: FetchReport,
+    },
+}
+
+/// A file that could not be downloaded, even after retrying.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailedDownload {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+/// What happened to every file during a fetch.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FetchReport {
+    pub downloaded: Vec<PathBuf>,
+    pub skipped: Vec<PathBuf>,
+    pub failed: Vec<FailedDownload>,
+}
+
+impl FetchReport {
+    pub fn merge(&mut self, other: FetchReport) {
+        self.downloaded.extend(other.downloaded);
+        self.skipped.extend(other.skipped);
+        self.failed.extend(other.failed);
+    }
+
+    /// Whether every file was either downloaded or skipped.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+
+    /// Turns the report into an error if any of the `required` files in `dir` failed to download
+    /// or doesn't exist.
+    fn require(self, dir: &Path, required: &[&str]) -> Result<Self, FetchError> {
+        let failed: Vec<String> = required
+            .iter()
+            .map(|file| dir.join(file))
+            .filter(|path| self.failed.iter().any(|failed| failed.path == *path))
+            .map(|path| path.display().to_string())
+            .collect();
+
+        if !failed.is_empty() {
+            return Err(FetchError::FailedRequired {
+                failed,
+                report: self,
+            });
+        }
+
+        let missing: Vec<String> = required
+            .iter()
+            .filter(|file| !dir.join(file).is_file())
+            .map(|file| dir.join(file).display().to_string())
+            .collect();
+
+        if missing.is_empty() {
+            Ok(self)
+        } else {
+            Err(FetchError::MissingRequired {
+                missing,
+                report: self,
+            })
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -32,6 +121,12 @@ pub enum CacheStrategy {
     None,
     Basic,
     Duration(Duration),
+
+    /// Only downloads files whose content actually changed.
+    ///
+    /// GitHub files are compared against the blob SHA from the tree response, the drops are
+    /// requested conditionally using the stored `ETag`/`Last-Modified` validators.
+    Conditional,
 }
 
 #[derive(Deserialize)]
@@ -43,16 +138,210 @@ struct GitHubTreeResponse {
 struct GitHubTreeEntry {
     path: String,
     r#type: String,
+    sha: String,
+}
+
+/// Name of the file the HTTP validators are stored in, inside the target directory.
+const VALIDATORS_FILE: &str = ".validators.json";
+
+/// HTTP validators of a previously downloaded file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Validators {
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl Validators {
+    fn from_response(response: &Response) -> Self {
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_owned)
+        };
+
+        Self {
+            etag: header(ETAG),
+            last_modified: header(LAST_MODIFIED),
+        }
+    }
+
+    fn apply(&self, mut request: RequestBuilder) -> RequestBuilder {
+        if let Some(etag) = &self.etag {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+
+        if let Some(last_modified) = &self.last_modified {
+            request = request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+
+        request
+    }
+}
+
+async fn load_validators(dir: &Path) -> HashMap<String, Validators> {
+    fs::read(dir.join(VALIDATORS_FILE))
+        .await
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .unwrap_or_default()
+}
+
+async fn store_validators(
+    dir: &Path,
+    validators: &HashMap<String, Validators>,
+) -> Result<(), FetchError> {
+    let json = serde_json::to_vec_pretty(validators).map_err(std::io::Error::other)?;
+    fs::write(dir.join(VALIDATORS_FILE), json).await?;
+
+    Ok(())
+}
+
+/// Computes the git blob SHA-1 of a local file, as reported by the GitHub tree API.
+async fn git_blob_sha(path: &Path) -> Option<String> {
+    let content = fs::read(path).await.ok()?;
+
+    let mut hasher = Sha1::new();
+    hasher.update(format!("blob {}\0", content.len()));
+    hasher.update(&content);
+
+    Some(
+        hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect(),
+    )
+}
+
+/// A progress event emitted while fetching.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FetchProgress {
+    /// The server responded and the body of `path` is being downloaded.
+    Started {
+        path: PathBuf,
+        total_bytes: Option<u64>,
+    },
+
+    /// Another chunk of `path` was received. `received` is the total received so far.
+    BytesReceived {
+        path: PathBuf,
+        received: u64,
+        total_bytes: Option<u64>,
+    },
+
+    /// `path` was downloaded and written to disk.
+    Finished { path: PathBuf },
+
+    /// `path` wasn't downloaded because the cached copy is still valid.
+    Skipped { path: PathBuf },
+
+    /// `path` could not be downloaded, even after retrying.
+    Failed { path: PathBuf, message: String },
+}
+
+/// Forwards [`FetchProgress`] events to the callback set with [`DataFetcher::on_progress`].
+#[derive(Clone, Default)]
+struct Progress(Option<Arc<dyn Fn(FetchProgress) + Send + Sync>>);
+
+impl Progress {
+    fn emit(&self, event: FetchProgress) {
+        if let Some(callback) = &self.0 {
+            callback(event);
+        }
+    }
+}
+
+/// Reads the body of `res` chunk by chunk, reporting progress for `path`.
+async fn read_body(
+    mut res: Response,
+    path: &Path,
+    progress: &Progress,
+) -> Result<Vec<u8>, reqwest::Error> {
+    let total_bytes = res.content_length();
+    let mut body = Vec::with_capacity(total_bytes.unwrap_or_default() as usize);
+
+    progress.emit(FetchProgress::Started {
+        path: path.to_owned(),
+        total_bytes,
+    });
+
+    while let Some(chunk) = res.chunk().await? {
+        body.extend_from_slice(&chunk);
+
+        progress.emit(FetchProgress::BytesReceived {
+            path: path.to_owned(),
+            received: body.len() as u64,
+            total_bytes,
+        });
+    }
+
+    Ok(body)
 }
 
 const USER_AGENT: &str = "Rust-Worldstate-Parser";
 
+const REQUIRED_DATA_FILES: &[&str] = &["languages.json", "solNodes.json", "sortieData.json"];
+
+const REQUIRED_DROPS_FILES: &[&str] = &["data.json"];
+
+const REQUIRED_ASSETS_FILES: &[&str] = &[
+    "archonHuntRewards.json",
+    "archonShardsStoreItem.json",
+    "crewBattleNodes.json",
+    "languageItemsExt.json",
+];
+
+/// Writes `contents` to a temporary file next to `path` and renames it into place, so readers
+/// never see a partially written file.
+async fn write_atomic(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let tmp_path = path.with_added_extension("tmp");
+
+    fs::write(&tmp_path, contents).await?;
+    fs::rename(&tmp_path, path).await
+}
+
+/// Runs `attempt` until it succeeds, retrying up to `retries` times with exponential backoff.
+async fn with_retries<T, E, Fut>(
+    retries: u32,
+    backoff: Duration,
+    mut attempt: impl FnMut() -> Fut,
+) -> Result<T, E>
+where
+    Fut: Future<Output = Result<T, E>>,
+    E: std::fmt::Display,
+{
+    let mut delay = backoff;
+
+    for _ in 0..retries {
+        match attempt().await {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                debug!("Attempt failed, retrying in {delay:?}: {err}");
+                tokio::time::sleep(delay).await;
+                delay *= 2;
+            },
+        }
+    }
+
+    attempt().await
+}
+
 pub struct DataFetcher {
     client: Client,
     data_dir: PathBuf,
     drops_dir: PathBuf,
     assets_dir: PathBuf,
     cache_strategy: CacheStrategy,
+    endpoints: Endpoints,
+    data_revision: Option<String>,
+    assets_revision: Option<String>,
+    max_concurrent_downloads: usize,
+    retries: u32,
+    retry_backoff: Duration,
+    progress: Progress,
 }
 
 impl Default for DataFetcher {
@@ -72,6 +361,13 @@ impl DataFetcher {
             drops_dir: PathBuf::from("drops"),
             assets_dir: PathBuf::from("assets"),
             cache_strategy: CacheStrategy::None,
+            endpoints: Endpoints::default(),
+            data_revision: None,
+            assets_revision: None,
+            max_concurrent_downloads: 8,
+            retries: 3,
+            retry_backoff: Duration::from_millis(500),
+            progress: Progress::default(),
         })
     }
 
@@ -99,71 +395,193 @@ impl DataFetcher {
         self
     }
 
+    /// Sets the endpoints to download from.
+    pub fn endpoints(mut self, endpoints: Endpoints) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    /// Pins WFCD/warframe-worldstate-data to a commit instead of the tip of `master`.
+    pub fn pin_data(mut self, commit: impl Into<String>) -> Self {
+        self.data_revision = Some(commit.into());
+        self
+    }
+
+    /// Pins WFCD/worldstate_parser_rs_assets to a commit instead of the tip of `main`.
+    pub fn pin_assets(mut self, commit: impl Into<String>) -> Self {
+        self.assets_revision = Some(commit.into());
+        self
+    }
+
+    /// Sets how many files are downloaded at the same time. Defaults to 8.
+    pub fn max_concurrent_downloads(mut self, max: usize) -> Self {
+        self.max_concurrent_downloads = max.max(1);
+        self
+    }
+
+    /// Sets how often a failed download is retried, and the delay before the first retry. The
+    /// delay doubles with every retry. Defaults to 3 retries starting at 500ms.
+    pub fn retries(mut self, retries: u32, backoff: Duration) -> Self {
+        self.retries = retries;
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Sets a callback that receives [`FetchProgress`] events for every file.
+    ///
+    /// Downloads run concurrently, so the callback may be called from several tasks at once. To
+    /// consume the events elsewhere, send them into a channel:
+    ///
+    /// ```no_run
+    /// # use worldstate_parser::default_data_fetcher::DataFetcher;
+    /// let (tx, rx) = std::sync::mpsc::channel();
+    ///
+    /// let fetcher = DataFetcher::default().on_progress(move |event| {
+    ///     let _ = tx.send(event);
+    /// });
+    /// ```
+    pub fn on_progress(mut self, callback: impl Fn(FetchProgress) + Send + Sync + 'static) -> Self {
+        self.progress = Progress(Some(Arc::new(callback)));
+        self
+    }
+
     /// Fetches all necessary data files.
-    pub async fn fetch_all(&self) -> Result<(), FetchError> {
-        self.fetch_data().await?;
-        self.fetch_drops().await?;
-        self.fetch_assets().await?;
-        Ok(())
+    pub async fn fetch_all(&self) -> Result<FetchReport, FetchError> {
+        let mut report = self.fetch_data().await?;
+        report.merge(self.fetch_drops().await?);
+        report.merge(self.fetch_assets().await?);
+        Ok(report)
     }
 
     /// Fetches data from WFCD/warframe-worldstate-data.
-    pub async fn fetch_data(&self) -> Result<(), FetchError> {
+    pub async fn fetch_data(&self) -> Result<FetchReport, FetchError> {
         info!("Fetching data from WFCD/warframe-worldstate-data...");
         self.fetch_github_folder(
             "WFCD",
             "warframe-worldstate-data",
-            "master",
+            self.data_revision.as_deref().unwrap_or("master"),
             "data",
             &self.data_dir,
         )
-        .await
+        .await?
+        .require(&self.data_dir, REQUIRED_DATA_FILES)
     }
 
-    /// Fetches drops from warframestat.us.
-    pub async fn fetch_drops(&self) -> Result<(), FetchError> {
-        let url = "https://drops.warframestat.us/data/all.json";
+    /// Fetches drops from warframestat.us (or the configured [`Endpoints::drops`]).
+    pub async fn fetch_drops(&self) -> Result<FetchReport, FetchError> {
+        let url = &self.endpoints.drops;
         let target_path = self.drops_dir.join("data.json");
+        let mut report = FetchReport::default();
 
         if self.should_skip(&target_path).await {
             info!("Skipping drops download (cached)");
-            return Ok(());
+            self.progress.emit(FetchProgress::Skipped {
+                path: target_path.clone(),
+            });
+            report.skipped.push(target_path);
+            return report.require(&self.drops_dir, REQUIRED_DROPS_FILES);
         }
 
         fs::create_dir_all(&self.drops_dir).await?;
 
+        let conditional = self.cache_strategy == CacheStrategy::Conditional;
+        let mut validators = if conditional {
+            load_validators(&self.drops_dir).await
+        } else {
+            HashMap::new()
+        };
+
+        let stored = validators
+            .get("data.json")
+            .filter(|_| conditional && target_path.exists())
+            .cloned();
+
         info!("Downloading {} to {}...", url, target_path.display());
-        let res = self.client.get(url).send().await?;
-        let bytes = res.bytes().await?;
-        fs::write(target_path, bytes).await?;
-        info!("Drops download complete.");
 
-        Ok(())
+        let result = with_retries(self.retries, self.retry_backoff, || async {
+            let mut request = self.client.get(url);
+
+            if let Some(stored) = &stored {
+                request = stored.apply(request);
+            }
+
+            let res = request.send().await?;
+
+            if res.status() == StatusCode::NOT_MODIFIED {
+                return Ok(None);
+            }
+
+            let res = res.error_for_status()?;
+            let new_validators = Validators::from_response(&res);
+            let bytes = read_body(res, &target_path, &self.progress).await?;
+
+            Ok::<_, reqwest::Error>(Some((bytes, new_validators)))
+        })
+        .await;
+
+        match result {
+            Ok(None) => {
+                info!("Skipping drops download (not modified)");
+                self.progress.emit(FetchProgress::Skipped {
+                    path: target_path.clone(),
+                });
+                report.skipped.push(target_path);
+            },
+            Ok(Some((bytes, new_validators))) => {
+                write_atomic(&target_path, &bytes).await?;
+
+                if conditional {
+                    validators.insert("data.json".to_owned(), new_validators);
+                    store_validators(&self.drops_dir, &validators).await?;
+                }
+
+                info!("Drops download complete.");
+                self.progress.emit(FetchProgress::Finished {
+                    path: target_path.clone(),
+                });
+                report.downloaded.push(target_path);
+            },
+            Err(err) => {
+                error!("Download error: {}", err);
+                self.progress.emit(FetchProgress::Failed {
+                    path: target_path.clone(),
+                    message: err.to_string(),
+                });
+                report.failed.push(FailedDownload {
+                    path: target_path,
+                    message: err.to_string(),
+                });
+            },
+        }
+
+        report.require(&self.drops_dir, REQUIRED_DROPS_FILES)
     }
 
     /// Fetches assets from https://github.com/WFCD/worldstate_parser_rs_assets using the 'main' branch
-    pub async fn fetch_assets(&self) -> Result<(), FetchError> {
+    /// (or the pinned commit)
+    pub async fn fetch_assets(&self) -> Result<FetchReport, FetchError> {
         info!("Fetching assets from WFCD/worldstate_parser_rs_assets...");
         self.fetch_github_folder(
             "WFCD",
             "worldstate_parser_rs_assets",
-            "main",
+            self.assets_revision.as_deref().unwrap_or("main"),
             "",
             &self.assets_dir,
         )
-        .await
+        .await?
+        .require(&self.assets_dir, REQUIRED_ASSETS_FILES)
     }
 
     async fn fetch_github_folder(
         &self,
         owner: &str,
         repo: &str,
-        branch: &str,
+        revision: &str,
         folder: &str,
         target_dir: &Path,
-    ) -> Result<(), FetchError> {
-        let api_url =
-            format!("https://api.github.com/repos/{owner}/{repo}/git/trees/{branch}?recursive=1");
+    ) -> Result<FetchReport, FetchError> {
+        let commit = self.resolve_commit(owner, repo, revision).await?;
+        let api_url = self.endpoints.github_tree_url(owner, repo, &commit);
 
         let response = self
             .client
@@ -197,7 +615,14 @@ impl DataFetcher {
             })
             .collect();
 
-        let mut handles = Vec::new();
+        let mut report = FetchReport::default();
+        let mut downloads = Vec::new();
+
+        // SHA-256 of every file known to match `commit`, keyed by file name
+        let mut hashes = BTreeMap::new();
+        // Files kept because of their mtime may be from any commit, so they can't be pinned
+        let mut pinnable = true;
+
         for entry in files_to_download {
             let file_name = Path::new(&entry.path).file_name().unwrap().to_owned();
             let target_path = target_dir.join(file_name);
@@ -207,58 +632,163 @@ impl DataFetcher {
                     "Skipping download for repo {repo} at {target_path} (cached)",
                     target_path = target_path.to_str().unwrap_or("")
                 );
+                self.progress.emit(FetchProgress::Skipped {
+                    path: target_path.clone(),
+                });
+                report.skipped.push(target_path);
+                pinnable = false;
                 continue;
             }
 
-            let client = self.client.clone();
-            let raw_url = format!(
-                "https://raw.githubusercontent.com/{owner}/{repo}/{branch}/{}",
-                entry.path
-            );
-            let path_clone = entry.path.clone();
-
-            handles.push(tokio::spawn(async move {
-                debug!("Downloading {}...", path_clone);
-                let res = client
-                    .get(&raw_url)
-                    .send()
-                    .await
-                    .map_err(|e| e.to_string())?;
-                if !res.status().is_success() {
-                    return Err(format!("Status {}", res.status()));
+            if self.cache_strategy == CacheStrategy::Conditional
+                && git_blob_sha(&target_path).await.as_deref() == Some(entry.sha.as_str())
+            {
+                debug!(
+                    "Skipping download for repo {repo} at {target_path} (unchanged)",
+                    target_path = target_path.to_str().unwrap_or("")
+                );
+                // The blob SHA matched the tree, so the file is the one of `commit`
+                if let Ok(contents) = fs::read(&target_path).await {
+                    hashes.insert(file_name_of(&target_path), sha256_hex(&contents));
                 }
-                let bytes = res.bytes().await.map_err(|e| e.to_string())?;
-                fs::write(target_path, bytes)
-                    .await
-                    .map_err(|e| e.to_string())?;
-                Ok::<(), String>(())
-            }));
+
+                self.progress.emit(FetchProgress::Skipped {
+                    path: target_path.clone(),
+                });
+                report.skipped.push(target_path);
+                continue;
+            }
+
+            let raw_url = self
+                .endpoints
+                .github_raw_url(owner, repo, &commit, &entry.path);
+
+            downloads.push((raw_url, target_path));
         }
 
-        if !handles.is_empty() {
+        if !downloads.is_empty() {
             info!(
                 "Downloading {} new/updated files from {}/{}.",
-                handles.len(),
+                downloads.len(),
                 owner,
                 repo
             );
         }
 
-        for handle in handles {
-            match handle.await? {
-                Ok(_) => {},
-                Err(e) => {
-                    error!("Download error: {}", e);
+        let mut pending = downloads.into_iter();
+        let mut tasks = JoinSet::new();
+
+        loop {
+            while tasks.len() < self.max_concurrent_downloads
+                && let Some((raw_url, target_path)) = pending.next()
+            {
+                let client = self.client.clone();
+                let progress = self.progress.clone();
+                let (retries, backoff) = (self.retries, self.retry_backoff);
+
+                tasks.spawn(async move {
+                    debug!("Downloading {}...", raw_url);
+
+                    let result = with_retries(retries, backoff, || {
+                        download_file(&client, &raw_url, &target_path, &progress)
+                    })
+                    .await;
+
+                    (target_path, result)
+                });
+            }
+
+            let Some(joined) = tasks.join_next().await else {
+                break;
+            };
+
+            match joined? {
+                (path, Ok(hash)) => {
+                    hashes.insert(file_name_of(&path), hash);
+                    self.progress
+                        .emit(FetchProgress::Finished { path: path.clone() });
+                    report.downloaded.push(path);
+                },
+                (path, Err(message)) => {
+                    error!("Download error for {}: {}", path.display(), message);
+                    self.progress.emit(FetchProgress::Failed {
+                        path: path.clone(),
+                        message: message.clone(),
+                    });
+                    report.failed.push(FailedDownload { path, message });
                 },
             }
         }
 
+        if pinnable && report.is_success() {
+            self.write_lock(owner, repo, commit, target_dir, hashes)
+                .await?;
+        } else if !report.downloaded.is_empty() {
+            // The files changed, so an older lockfile no longer matches them
+            debug!("Not pinning {owner}/{repo}, removing the lockfile");
+
+            match fs::remove_file(lock_path(target_dir)?).await {
+                Err(err) if err.kind() != std::io::ErrorKind::NotFound => return Err(err.into()),
+                _ => {},
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Resolves a branch (or any other git reference) to a commit SHA.
+    async fn resolve_commit(
+        &self,
+        owner: &str,
+        repo: &str,
+        revision: &str,
+    ) -> Result<String, FetchError> {
+        let is_commit_sha = revision.len() == 40 && revision.chars().all(|c| c.is_ascii_hexdigit());
+
+        if is_commit_sha {
+            return Ok(revision.to_owned());
+        }
+
+        let response = self
+            .client
+            .get(self.endpoints.github_commit_url(owner, repo, revision))
+            .header("Accept", "application/vnd.github.sha")
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(FetchError::GitHub(format!(
+                "Could not resolve {owner}/{repo}@{revision}: status {}",
+                response.status()
+            )));
+        }
+
+        Ok(response.text().await?.trim().to_owned())
+    }
+
+    /// Writes the lockfile for `target_dir`, pinning `files` (file name -> SHA-256) to `commit`.
+    async fn write_lock(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit: String,
+        target_dir: &Path,
+        files: BTreeMap<String, String>,
+    ) -> Result<(), FetchError> {
+        let lock = BundleLock {
+            repo: format!("{owner}/{repo}"),
+            commit,
+            files,
+        };
+
+        fs::write(lock_path(target_dir)?, lock.to_vec()).await?;
+
         Ok(())
     }
 
     async fn should_skip(&self, path: &Path) -> bool {
         match self.cache_strategy {
-            CacheStrategy::None => false,
+            CacheStrategy::None | CacheStrategy::Conditional => false,
             CacheStrategy::Basic => path.exists(),
             CacheStrategy::Duration(duration) => {
                 if let Ok(metadata) = fs::metadata(path).await
@@ -274,10 +804,241 @@ impl DataFetcher {
     }
 }
 
-/// Helper function to fetch everything using default settings and no caching.
-pub async fn fetch_all(cache_strategy: CacheStrategy) -> Result<(), FetchError> {
+/// `data/languages.json` -> `languages.json`
+fn file_name_of(path: &Path) -> String {
+    path.file_name()
+        .map(|file_name| file_name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Downloads `url` to `target_path` and returns the SHA-256 of the downloaded bytes.
+async fn download_file(
+    client: &Client,
+    url: &str,
+    target_path: &Path,
+    progress: &Progress,
+) -> Result<String, String> {
+    let res = client.get(url).send().await.map_err(|e| e.to_string())?;
+
+    if !res.status().is_success() {
+        return Err(format!("Status {}", res.status()));
+    }
+
+    let bytes = read_body(res, target_path, progress)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    write_atomic(target_path, &bytes)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(sha256_hex(&bytes))
+}
+
+/// Helper function to fetch everything using default settings.
+pub async fn fetch_all(cache_strategy: CacheStrategy) -> Result<FetchReport, FetchError> {
     DataFetcher::new()?
         .cache_strategy(cache_strategy)
         .fetch_all()
         .await
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        cell::Cell,
+        io::{Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use crate::{
+        default_data_fetcher::{
+            CacheStrategy,
+            DataFetcher,
+            FailedDownload,
+            FetchError,
+            FetchProgress,
+            FetchReport,
+            git_blob_sha,
+            with_retries,
+        },
+        endpoints::Endpoints,
+    };
+
+    /// Serves one canned HTTP response per connection, in order, and returns the base URL.
+    fn serve(responses: Vec<&'static str>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        std::thread::spawn(move || {
+            for response in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let _ = stream.read(&mut [0; 4096]).unwrap();
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        url
+    }
+
+    /// A fetcher for `dir` that downloads the drops from `url` and records every event.
+    fn fetcher(
+        dir: &std::path::Path,
+        url: String,
+    ) -> (DataFetcher, Arc<Mutex<Vec<FetchProgress>>>) {
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+
+        let fetcher = DataFetcher::new()
+            .unwrap()
+            .drops_dir(dir)
+            .endpoints(Endpoints {
+                drops: url,
+                ..Default::default()
+            })
+            .retries(0, Duration::from_millis(1))
+            .cache_strategy(CacheStrategy::Basic)
+            .on_progress(move |event| sink.lock().unwrap().push(event));
+
+        (fetcher, events)
+    }
+
+    #[tokio::test]
+    async fn test_with_retries() {
+        let attempts = Cell::new(0);
+
+        let attempt = || async {
+            attempts.set(attempts.get() + 1);
+
+            match attempts.get() {
+                3 => Ok(()),
+                _ => Err("failed"),
+            }
+        };
+
+        assert_eq!(
+            with_retries(1, Duration::from_millis(1), attempt).await,
+            Err("failed")
+        );
+
+        attempts.set(0);
+
+        assert_eq!(
+            with_retries(2, Duration::from_millis(1), attempt).await,
+            Ok(())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_git_blob_sha() {
+        let path = std::env::temp_dir().join(format!("git_blob_sha_{}", std::process::id()));
+        std::fs::write(&path, "hello\n").unwrap();
+
+        // `echo hello | git hash-object --stdin`
+        assert_eq!(
+            git_blob_sha(&path).await.as_deref(),
+            Some("ce013625030ba8dba906f756967f9e9ca394464a")
+        );
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_progress_events() {
+        let dir = std::env::temp_dir().join(format!("fetch_progress_{}", std::process::id()));
+        let path = dir.join("data.json");
+
+        let url = serve(vec![
+            "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}",
+        ]);
+        let (fetcher, events) = fetcher(&dir, url);
+
+        fetcher.fetch_drops().await.unwrap();
+        // Cached now
+        fetcher.fetch_drops().await.unwrap();
+
+        let events = events.lock().unwrap().clone();
+
+        assert_eq!(
+            events.first(),
+            Some(&FetchProgress::Started {
+                path: path.clone(),
+                total_bytes: Some(2),
+            })
+        );
+        assert!(
+            events[1..events.len() - 2]
+                .iter()
+                .all(|event| matches!(event, FetchProgress::BytesReceived { .. }))
+        );
+        assert_eq!(
+            events[events.len() - 3],
+            FetchProgress::BytesReceived {
+                path: path.clone(),
+                received: 2,
+                total_bytes: Some(2),
+            }
+        );
+        assert_eq!(
+            events[events.len() - 2..],
+            [
+                FetchProgress::Finished { path: path.clone() },
+                FetchProgress::Skipped { path: path.clone() },
+            ]
+        );
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_progress_failed() {
+        let dir = std::env::temp_dir().join(format!("fetch_failed_{}", std::process::id()));
+
+        let url = serve(vec![
+            "HTTP/1.1 500 Internal Server Error\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        ]);
+        let (fetcher, events) = fetcher(&dir, url);
+
+        assert!(matches!(
+            fetcher.fetch_drops().await,
+            Err(FetchError::FailedRequired { .. })
+        ));
+        assert!(matches!(
+            events.lock().unwrap().as_slice(),
+            [FetchProgress::Failed { path, .. }] if *path == dir.join("data.json")
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_require() {
+        let dir = std::env::temp_dir().join(format!("fetch_require_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("data.json"), "{}").unwrap();
+
+        assert!(FetchReport::default().require(&dir, &["data.json"]).is_ok());
+        assert!(matches!(
+            FetchReport::default().require(&dir, &["solNodes.json"]),
+            Err(FetchError::MissingRequired { missing, .. }) if missing.len() == 1
+        ));
+
+        // A stale copy on disk doesn't hide the failed download
+        let report = FetchReport {
+            failed: vec![FailedDownload {
+                path: dir.join("data.json"),
+                message: "Status 500".to_owned(),
+            }],
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            report.require(&dir, &["data.json"]),
+            Err(FetchError::FailedRequired { failed, .. }) if failed.len() == 1
+        ));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
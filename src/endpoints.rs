@@ -0,0 +1,135 @@
+use serde::{Deserialize, Serialize};
+
+fn resolve_url(base: &str, path: String) -> String {
+    if path.starts_with('/') {
+        format!("{}{path}", base.trim_end_matches('/'))
+    } else {
+        path
+    }
+}
+
+/// Base URLs of every remote source the fetcher and the default context provider talk to, and of
+/// the sites the parsed worldstate links to.
+///
+/// Override single fields to point them at a mirror or a local test server:
+///
+/// ```
+/// use worldstate_parser::endpoints::Endpoints;
+///
+/// let endpoints = Endpoints {
+///     drops: "http://localhost:8080/drops/all.json".to_owned(),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Endpoints {
+    /// The LZMA compressed export index.
+    pub export_index: String,
+
+    /// Base URL of the export manifests. The manifest key is appended as the last path segment.
+    pub export_manifest: String,
+
+    /// Base URL of the GitHub REST API.
+    pub github_api: String,
+
+    /// Base URL for raw files on GitHub.
+    pub github_raw: String,
+
+    /// The drop tables (`all.json`).
+    pub drops: String,
+
+    /// Base URL for game asset paths like `/Lotus/Interface/Icons/DiscordIconNoBacker.png`.
+    pub content: String,
+
+    /// Base URL for site-relative links like `/news/some-article`.
+    pub website: String,
+}
+
+impl Default for Endpoints {
+    fn default() -> Self {
+        Self {
+            export_index: "https://origin.warframe.com/PublicExport/index_en.txt.lzma".to_owned(),
+            export_manifest: "http://content.warframe.com/PublicExport/Manifest".to_owned(),
+            github_api: "https://api.github.com".to_owned(),
+            github_raw: "https://raw.githubusercontent.com".to_owned(),
+            drops: "https://drops.warframestat.us/data/all.json".to_owned(),
+            content: "https://content.warframe.com/PublicExport".to_owned(),
+            website: "https://www.warframe.com".to_owned(),
+        }
+    }
+}
+
+impl Endpoints {
+    /// Prefixes a game asset path with [`Endpoints::content`]. Absolute URLs are kept as they are.
+    pub fn content_url(&self, path: String) -> String {
+        resolve_url(&self.content, path)
+    }
+
+    /// Prefixes a site-relative link with [`Endpoints::website`]. Absolute URLs are kept as they
+    /// are.
+    pub fn website_url(&self, path: String) -> String {
+        resolve_url(&self.website, path)
+    }
+
+    pub fn manifest_url(&self, manifest: &str) -> String {
+        format!("{}/{manifest}", self.export_manifest.trim_end_matches('/'))
+    }
+
+    pub fn github_commit_url(&self, owner: &str, repo: &str, reference: &str) -> String {
+        format!(
+            "{}/repos/{owner}/{repo}/commits/{reference}",
+            self.github_api.trim_end_matches('/')
+        )
+    }
+
+    pub fn github_tree_url(&self, owner: &str, repo: &str, tree: &str) -> String {
+        format!(
+            "{}/repos/{owner}/{repo}/git/trees/{tree}?recursive=1",
+            self.github_api.trim_end_matches('/')
+        )
+    }
+
+    pub fn github_raw_url(&self, owner: &str, repo: &str, tree: &str, path: &str) -> String {
+        format!(
+            "{}/{owner}/{repo}/{tree}/{path}",
+            self.github_raw.trim_end_matches('/')
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::endpoints::Endpoints;
+
+    #[test]
+    fn test_trailing_slashes() {
+        let endpoints = Endpoints {
+            export_manifest: "http://localhost/Manifest/".to_owned(),
+            github_api: "http://localhost/api/".to_owned(),
+            github_raw: "http://localhost/raw//".to_owned(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            endpoints.manifest_url("ExportRegions_en.json!00_a"),
+            "http://localhost/Manifest/ExportRegions_en.json!00_a"
+        );
+        assert_eq!(
+            endpoints.github_commit_url("WFCD", "warframe-worldstate-data", "master"),
+            "http://localhost/api/repos/WFCD/warframe-worldstate-data/commits/master"
+        );
+        assert_eq!(
+            endpoints.github_tree_url("WFCD", "warframe-worldstate-data", "abc"),
+            "http://localhost/api/repos/WFCD/warframe-worldstate-data/git/trees/abc?recursive=1"
+        );
+        assert_eq!(
+            endpoints.github_raw_url("WFCD", "warframe-worldstate-data", "abc", "data/x.json"),
+            "http://localhost/raw/WFCD/warframe-worldstate-data/abc/data/x.json"
+        );
+        assert_eq!(
+            Endpoints::default().manifest_url("ExportRegions_en.json!00_a"),
+            "http://content.warframe.com/PublicExport/Manifest/ExportRegions_en.json!00_a"
+        );
+    }
+}
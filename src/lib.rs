@@ -1,17 +1,40 @@
+#[cfg(feature = "local")]
+pub mod bundle_lock;
+pub mod catalogue;
+#[cfg(feature = "local")]
+pub mod context_snapshot;
+pub mod context_sources;
 pub(crate) mod core;
 pub(crate) mod custom_maps;
 #[cfg(feature = "default_provider")]
 pub mod default_context_provider;
 #[cfg(feature = "default_fetcher")]
 pub mod default_data_fetcher;
+pub mod endpoints;
+#[cfg(feature = "local")]
+pub mod local_context_provider;
+#[cfg(feature = "default_provider")]
+pub mod manifest_cache;
 pub(crate) mod manifest_entries;
+#[cfg(feature = "local")]
+pub mod manifest_versions;
 pub(crate) mod manifests;
+#[cfg(feature = "offline_bundle")]
+pub mod offline_bundle;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod relic_catalogue;
+pub mod shared_context;
 pub mod target_types;
+pub mod void_trader_history;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 pub(crate) mod wfcd_data;
 pub mod worldstate;
 pub(crate) mod worldstate_model;
 
 pub use crate::{
+    core::Context,
     target_types::{
         cycles,
         display_info::DisplayInfo,
@@ -49,3 +72,9 @@ pub trait ContextProvider {
     type Err;
     fn get_ctx(&self) -> impl Future<Output = Result<crate::core::Context, Self::Err>> + Send;
 }
+
+/// A [`ContextProvider`] that can load the [`Context`] without an async runtime.
+pub trait BlockingContextProvider {
+    type Err;
+    fn get_ctx_blocking(&self) -> Result<crate::core::Context, Self::Err>;
+}
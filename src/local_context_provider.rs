@@ -0,0 +1,297 @@
+//! A [`ContextProvider`] that never touches the network.
+//!
+//! The [`Context`] is built from files on disk ([`LocalContextProvider`]). To build it from byte
+//! buffers that are already in memory, use [`InMemoryContextProvider`].
+
+use std::{
+    fs,
+    io,
+    path::{Path, PathBuf},
+};
+
+pub use crate::context_sources::{
+    ContextSources,
+    ExportSources,
+    InMemoryContextProvider,
+    WorldstateDataError,
+    WorldstateDataSources,
+};
+use crate::{
+    BlockingContextProvider,
+    ContextProvider,
+    bundle_lock::{BundleLock, LockMismatchError},
+    catalogue::CatalogueSources,
+    core::Context,
+    manifest_versions::ManifestVersions,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PathContext<'a> {
+    pub data_dir: &'a Path,
+    pub drops_dir: &'a Path,
+    pub assets_dir: &'a Path,
+}
+
+fn read_json(dir: &Path, file: &str) -> io::Result<Vec<u8>> {
+    fs::read(dir.join(file).with_extension("json"))
+}
+
+/// Verifies that `dir` holds nothing but the files of its lockfile, if it has one.
+fn verify_entries(dir: &Path, lock: Option<&BundleLock>) -> Result<(), WorldstateDataError> {
+    let Some(lock) = lock else {
+        return Ok(());
+    };
+
+    let mut file_names = Vec::new();
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+
+        if entry.file_type()?.is_file() {
+            file_names.push(entry.file_name().to_string_lossy().into_owned());
+        }
+    }
+
+    Ok(lock.verify_entries(file_names)?)
+}
+
+/// Finds an export manifest in `dir`.
+///
+/// Accepts both the plain name (`ExportRegions_en.json`) and the hashed names the manifest cache
+/// writes (`ExportRegions_en.json!<hash>.json`). If several hashed versions exist, the newest one
+/// recorded in the [`ManifestVersions`] of `dir` is used. Only directories without a version list
+/// fall back to the most recently modified one.
+fn find_export(dir: &Path, name: &str) -> io::Result<PathBuf> {
+    let plain = dir.join(name);
+
+    if plain.is_file() {
+        return Ok(plain);
+    }
+
+    if let Some(newest) = ManifestVersions::read(dir)?.newest(name) {
+        let path = dir.join(newest);
+
+        if path.is_file() {
+            return Ok(path);
+        }
+    }
+
+    let prefix = format!("{name}!");
+
+    fs::read_dir(dir)?
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry.file_name().to_str().is_some_and(|file_name| {
+                file_name.starts_with(&prefix) && file_name.ends_with(".json")
+            })
+        })
+        .max_by_key(|entry| {
+            entry
+                .metadata()
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        })
+        .map(|entry| entry.path())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("{name} not found in {}", dir.display()),
+            )
+        })
+}
+
+impl ExportSources {
+    /// Reads the export manifests from `exports_dir` and `crewBattleNodes.json` from `assets_dir`.
+    ///
+    /// The catalogue exports are read as well if `exports_dir` has them.
+    pub fn from_dirs(exports_dir: &Path, assets_dir: &Path) -> io::Result<Self> {
+        Ok(Self {
+            regions: fs::read(find_export(exports_dir, "ExportRegions_en.json")?)?,
+            relic_arcane: fs::read(find_export(exports_dir, "ExportRelicArcane_en.json")?)?,
+            customs: fs::read(find_export(exports_dir, "ExportCustoms_en.json")?)?,
+            crew_battle_nodes: read_json(assets_dir, "crewBattleNodes")?,
+            catalogue: CatalogueSources::from_dir(exports_dir)?,
+        })
+    }
+
+    /// Verifies `crewBattleNodes.json` against the lockfile of the assets, if any.
+    pub fn verify(&self, assets_lock: Option<&BundleLock>) -> Result<(), LockMismatchError> {
+        match assets_lock {
+            Some(lock) => lock.verify("crewBattleNodes.json", &self.crew_battle_nodes),
+            None => Ok(()),
+        }
+    }
+}
+
+impl CatalogueSources {
+    /// Reads the catalogue exports from `dir`. `None` if `dir` has none of them, an error if only
+    /// some are present.
+    pub fn from_dir(dir: &Path) -> io::Result<Option<Self>> {
+        if find_export(dir, Self::MANIFESTS[0]).is_err() {
+            return Ok(None);
+        }
+
+        let mut manifests = Vec::with_capacity(Self::MANIFESTS.len());
+
+        for name in Self::MANIFESTS {
+            manifests.push(fs::read(find_export(dir, name)?)?);
+        }
+
+        Ok(manifests.try_into().ok().map(Self::from_manifests))
+    }
+}
+
+impl WorldstateDataSources {
+    pub fn from_dirs(
+        PathContext {
+            data_dir,
+            drops_dir,
+            assets_dir,
+        }: PathContext<'_>,
+    ) -> io::Result<Self> {
+        Ok(Self {
+            languages: read_json(data_dir, "languages")?,
+            sol_nodes: read_json(data_dir, "solNodes")?,
+            sortie_data: read_json(data_dir, "sortieData")?,
+            drops: read_json(drops_dir, "data")?,
+            language_items_ext: read_json(assets_dir, "languageItemsExt")?,
+            archon_hunt_rewards: read_json(assets_dir, "archonHuntRewards")?,
+            archon_shards_store_item: read_json(assets_dir, "archonShardsStoreItem")?,
+        })
+    }
+
+    /// Verifies the WFCD data and assets files against their lockfiles, if any.
+    pub fn verify(
+        &self,
+        data_lock: Option<&BundleLock>,
+        assets_lock: Option<&BundleLock>,
+    ) -> Result<(), LockMismatchError> {
+        if let Some(lock) = data_lock {
+            lock.verify("languages.json", &self.languages)?;
+            lock.verify("solNodes.json", &self.sol_nodes)?;
+            lock.verify("sortieData.json", &self.sortie_data)?;
+        }
+
+        if let Some(lock) = assets_lock {
+            lock.verify("languageItemsExt.json", &self.language_items_ext)?;
+            lock.verify("archonHuntRewards.json", &self.archon_hunt_rewards)?;
+            lock.verify("archonShardsStoreItem.json", &self.archon_shards_store_item)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ContextSources {
+    /// Reads every source from disk. `exports_dir` holds the `Export*_en.json` manifests.
+    ///
+    /// If the data or assets directory has a lockfile, the files are verified against it, and the
+    /// directory may not hold any other files.
+    pub fn from_dirs(
+        paths: PathContext<'_>,
+        exports_dir: &Path,
+    ) -> Result<Self, WorldstateDataError> {
+        let worldstate_data = WorldstateDataSources::from_dirs(paths)?;
+        let exports = ExportSources::from_dirs(exports_dir, paths.assets_dir)?;
+        let data_lock = BundleLock::read(paths.data_dir)?;
+        let assets_lock = BundleLock::read(paths.assets_dir)?;
+
+        worldstate_data.verify(data_lock.as_ref(), assets_lock.as_ref())?;
+        exports.verify(assets_lock.as_ref())?;
+        verify_entries(paths.data_dir, data_lock.as_ref())?;
+        verify_entries(paths.assets_dir, assets_lock.as_ref())?;
+
+        Ok(Self {
+            exports,
+            worldstate_data,
+        })
+    }
+}
+
+/// Builds the [`Context`] from files on disk only.
+///
+/// The files are read synchronously, as this provider is meant for tests, sandboxes and
+/// air-gapped deployments rather than hot paths.
+#[derive(Debug, Clone, Copy)]
+pub struct LocalContextProvider<'a> {
+    pub paths: PathContext<'a>,
+
+    /// Directory containing the `Export*_en.json` manifests.
+    pub exports_dir: &'a Path,
+}
+
+impl BlockingContextProvider for LocalContextProvider<'_> {
+    type Err = WorldstateDataError;
+
+    fn get_ctx_blocking(&self) -> Result<Context, Self::Err> {
+        ContextSources::from_dirs(self.paths, self.exports_dir)?.build()
+    }
+}
+
+impl ContextProvider for LocalContextProvider<'_> {
+    type Err = WorldstateDataError;
+
+    async fn get_ctx(&self) -> Result<Context, Self::Err> {
+        self.get_ctx_blocking()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        bundle_lock::BundleLock,
+        context_snapshot::sha256_hex,
+        local_context_provider::{ExportSources, find_export},
+        manifest_versions::{ManifestVersions, VERSIONS_FILE},
+    };
+
+    #[test]
+    fn test_verify_crew_battle_nodes() {
+        let lock = BundleLock {
+            repo: "WFCD/worldstate_parser_rs_assets".to_owned(),
+            commit: "abc".to_owned(),
+            files: BTreeMap::from([("crewBattleNodes.json".to_owned(), sha256_hex(b"[]"))]),
+        };
+
+        let mut sources = ExportSources {
+            crew_battle_nodes: b"[]".to_vec(),
+            ..Default::default()
+        };
+
+        assert!(sources.verify(Some(&lock)).is_ok());
+
+        sources.crew_battle_nodes = b"[{}]".to_vec();
+
+        assert!(sources.verify(Some(&lock)).is_err());
+        assert!(sources.verify(None).is_ok());
+    }
+
+    #[test]
+    fn test_find_export() {
+        let dir = std::env::temp_dir().join(format!("find_export_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let mut versions = ManifestVersions::default();
+        versions.push("ExportRegions_en.json", "ExportRegions_en.json!b.json", 2);
+        std::fs::write(dir.join(VERSIONS_FILE), versions.to_vec()).unwrap();
+
+        // Written last, but not the version the cache recorded as the newest
+        for version in ["b", "a"] {
+            std::fs::write(
+                dir.join(format!("ExportRegions_en.json!{version}.json")),
+                "[]",
+            )
+            .unwrap();
+        }
+
+        assert_eq!(
+            find_export(&dir, "ExportRegions_en.json").unwrap(),
+            dir.join("ExportRegions_en.json!b.json")
+        );
+        assert!(find_export(&dir, "ExportCustoms_en.json").is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
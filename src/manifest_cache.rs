@@ -0,0 +1,185 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use tokio::fs;
+use tracing::debug;
+
+use crate::{
+    context_snapshot::sha256_hex,
+    manifest_versions::{ManifestVersions, VERSIONS_FILE},
+};
+
+/// File name of the cached (still LZMA compressed) export index.
+pub const INDEX_FILE: &str = "index_en.txt.lzma";
+
+/// On-disk cache for the export manifests from `content.warframe.com`.
+///
+/// Every manifest version is stored as `<export name>!<hash>.json`, where the hash is derived from
+/// the full manifest key (e.g. `ExportRegions_en.json!00_Ab3...`). Only the last
+/// [`ManifestCache::retain`] written versions of each export are kept, in the order recorded in
+/// [`VERSIONS_FILE`]. The last downloaded export index is
+/// kept as [`INDEX_FILE`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ManifestCache {
+    dir: PathBuf,
+    retain: usize,
+}
+
+impl Default for ManifestCache {
+    fn default() -> Self {
+        Self::new("./cache")
+    }
+}
+
+impl ManifestCache {
+    /// Creates a cache in `dir` that keeps only the latest version of each export.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            retain: 1,
+        }
+    }
+
+    /// Sets how many versions of each export are kept. At least one version is always kept.
+    pub fn retain(mut self, versions: usize) -> Self {
+        self.retain = versions.max(1);
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The path a manifest like `ExportRegions_en.json!00_Ab3...` is cached at.
+    pub fn path_for(&self, manifest: &str) -> PathBuf {
+        let hash = sha256_hex(manifest.as_bytes());
+
+        self.dir
+            .join(format!("{}!{}.json", export_name(manifest), &hash[..16]))
+    }
+
+    pub fn index_path(&self) -> PathBuf {
+        self.dir.join(INDEX_FILE)
+    }
+
+    /// Atomically stores the raw export index.
+    pub async fn write_index(&self, contents: &[u8]) -> io::Result<()> {
+        fs::create_dir_all(&self.dir).await?;
+
+        let path = self.index_path();
+        let tmp_path = path.with_added_extension("tmp");
+
+        fs::write(&tmp_path, contents).await?;
+        fs::rename(&tmp_path, &path).await
+    }
+
+    /// Returns the cached contents of `manifest`, if present.
+    pub async fn read(&self, manifest: &str) -> Option<String> {
+        let path = self.path_for(manifest);
+        let cached = fs::read_to_string(&path).await.ok()?;
+
+        debug!("Using cache at {}", path.display());
+
+        Some(cached)
+    }
+
+    /// Atomically stores `contents` for `manifest` and evicts old versions of the same export.
+    ///
+    /// Versions are evicted in the order they were written, as recorded in the
+    /// [`ManifestVersions`] of the cache directory.
+    pub async fn write(&self, manifest: &str, contents: &str) -> io::Result<()> {
+        fs::create_dir_all(&self.dir).await?;
+
+        let path = self.path_for(manifest);
+        let tmp_path = path.with_added_extension("tmp");
+
+        fs::write(&tmp_path, contents).await?;
+        fs::rename(&tmp_path, &path).await?;
+
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .expect("cache file names are always valid UTF-8");
+
+        self.evict(export_name(manifest), file_name).await
+    }
+
+    /// Records `file_name` as the newest version of `export_name` and removes the versions that
+    /// exceed [`ManifestCache::retain`].
+    async fn evict(&self, export_name: &str, file_name: &str) -> io::Result<()> {
+        let versions_path = self.dir.join(VERSIONS_FILE);
+
+        let mut versions = match fs::read(&versions_path).await {
+            Ok(bytes) => ManifestVersions::from_slice(&bytes)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => ManifestVersions::default(),
+            Err(err) => return Err(err),
+        };
+
+        let evicted = versions.push(export_name, file_name, self.retain);
+
+        let tmp_path = versions_path.with_added_extension("tmp");
+        fs::write(&tmp_path, versions.to_vec()).await?;
+        fs::rename(&tmp_path, &versions_path).await?;
+
+        for evicted in evicted {
+            let path = self.dir.join(evicted);
+            debug!("Evicting {}", path.display());
+
+            match fs::remove_file(path).await {
+                Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+                _ => {},
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// `ExportRegions_en.json!00_Ab3...` -> `ExportRegions_en.json`
+fn export_name(manifest: &str) -> &str {
+    manifest
+        .split_once('!')
+        .map_or(manifest, |(export_name, _)| export_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::manifest_cache::ManifestCache;
+
+    #[tokio::test]
+    async fn test_retention() {
+        let dir = std::env::temp_dir().join(format!("manifest_cache_{}", std::process::id()));
+        let cache = ManifestCache::new(&dir).retain(2);
+
+        for version in ["00_a", "00_b", "00_c"] {
+            let manifest = format!("ExportRegions_en.json!{version}");
+            cache.write(&manifest, version).await.unwrap();
+        }
+        cache.write("ExportCustoms_en.json!00_a", "").await.unwrap();
+
+        assert_eq!(
+            cache.read("ExportRegions_en.json!00_c").await.as_deref(),
+            Some("00_c")
+        );
+        assert_eq!(cache.read("ExportRegions_en.json!00_a").await, None);
+        assert!(cache.path_for("ExportRegions_en.json!00_b").is_file());
+        assert!(cache.path_for("ExportCustoms_en.json!00_a").is_file());
+
+        // Rewriting an old version makes it the newest one again
+        cache
+            .write("ExportRegions_en.json!00_b", "00_b")
+            .await
+            .unwrap();
+        cache
+            .write("ExportRegions_en.json!00_d", "00_d")
+            .await
+            .unwrap();
+
+        assert!(cache.path_for("ExportRegions_en.json!00_b").is_file());
+        assert!(!cache.path_for("ExportRegions_en.json!00_c").exists());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,282 @@
+//! Entries of the optional catalogue exports. Only the commonly used fields are kept, everything
+//! that isn't present in every entry is optional.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestWarframe {
+    pub unique_name: String,
+
+    pub name: String,
+
+    pub parent_name: Option<String>,
+
+    pub description: Option<String>,
+
+    pub product_category: Option<String>,
+
+    #[serde(default)]
+    pub codex_secret: bool,
+
+    #[serde(default)]
+    pub exclude_from_codex: bool,
+
+    #[serde(default)]
+    pub mastery_req: u32,
+
+    pub health: Option<f64>,
+
+    pub shield: Option<f64>,
+
+    pub armor: Option<f64>,
+
+    pub power: Option<f64>,
+
+    pub sprint_speed: Option<f64>,
+
+    #[serde(default)]
+    pub abilities: Vec<ManifestAbility>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestAbility {
+    pub ability_unique_name: String,
+
+    pub ability_name: String,
+
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestWeapon {
+    pub unique_name: String,
+
+    pub name: String,
+
+    pub description: Option<String>,
+
+    pub product_category: Option<String>,
+
+    #[serde(default)]
+    pub codex_secret: bool,
+
+    #[serde(default)]
+    pub exclude_from_codex: bool,
+
+    #[serde(default)]
+    pub mastery_req: u32,
+
+    pub slot: Option<u32>,
+
+    pub total_damage: Option<f64>,
+
+    #[serde(default)]
+    pub damage_per_shot: Vec<f64>,
+
+    pub critical_chance: Option<f64>,
+
+    pub critical_multiplier: Option<f64>,
+
+    pub proc_chance: Option<f64>,
+
+    pub fire_rate: Option<f64>,
+
+    pub magazine_size: Option<u32>,
+
+    pub reload_time: Option<f64>,
+
+    pub multishot: Option<f64>,
+
+    pub omega_attenuation: Option<f64>,
+}
+
+/// An entry of `ExportResources`, `ExportKeys`, `ExportGear` or `ExportFlavour`.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestItem {
+    pub unique_name: String,
+
+    pub name: String,
+
+    pub parent_name: Option<String>,
+
+    pub description: Option<String>,
+
+    pub product_category: Option<String>,
+
+    #[serde(default)]
+    pub codex_secret: bool,
+
+    #[serde(default)]
+    pub exclude_from_codex: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestUpgrade {
+    pub unique_name: String,
+
+    pub name: String,
+
+    pub polarity: Option<String>,
+
+    pub rarity: Option<String>,
+
+    #[serde(rename = "type")]
+    pub upgrade_type: Option<String>,
+
+    pub compat_name: Option<String>,
+
+    #[serde(default)]
+    pub base_drain: i32,
+
+    #[serde(default)]
+    pub fusion_limit: u32,
+
+    #[serde(default)]
+    pub is_utility: bool,
+
+    #[serde(default)]
+    pub codex_secret: bool,
+
+    #[serde(default)]
+    pub exclude_from_codex: bool,
+
+    #[serde(default)]
+    pub description: Vec<String>,
+
+    #[serde(default)]
+    pub level_stats: Vec<ManifestLevelStats>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct ManifestLevelStats {
+    pub stats: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestRecipe {
+    pub unique_name: String,
+
+    /// The unique name of the crafted item.
+    pub result_type: String,
+
+    #[serde(default)]
+    pub build_price: u64,
+
+    /// In seconds.
+    #[serde(default)]
+    pub build_time: u64,
+
+    #[serde(default)]
+    pub skip_build_time_price: u64,
+
+    #[serde(default)]
+    pub consume_on_use: bool,
+
+    #[serde(default = "default_count")]
+    pub num: u32,
+
+    #[serde(default)]
+    pub codex_secret: bool,
+
+    #[serde(default)]
+    pub ingredients: Vec<ManifestIngredient>,
+}
+
+fn default_count() -> u32 {
+    1
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "PascalCase")]
+pub struct ManifestIngredient {
+    pub item_type: String,
+
+    pub item_count: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestSentinel {
+    pub unique_name: String,
+
+    pub name: String,
+
+    pub description: Option<String>,
+
+    pub product_category: Option<String>,
+
+    #[serde(default)]
+    pub codex_secret: bool,
+
+    #[serde(default)]
+    pub exclude_from_codex: bool,
+
+    pub health: Option<f64>,
+
+    pub shield: Option<f64>,
+
+    pub armor: Option<f64>,
+
+    pub power: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestDrone {
+    pub unique_name: String,
+
+    pub name: String,
+
+    pub description: Option<String>,
+
+    #[serde(default)]
+    pub codex_secret: bool,
+
+    pub bin_count: Option<u32>,
+
+    pub bin_capacity: Option<u32>,
+
+    pub fill_rate: Option<f64>,
+
+    pub durability: Option<u32>,
+
+    pub repair_rate: Option<u32>,
+
+    #[serde(default)]
+    pub specialities: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestFusionBundle {
+    pub unique_name: String,
+
+    pub description: Option<String>,
+
+    #[serde(default)]
+    pub codex_secret: bool,
+
+    pub fusion_points: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestSortieReward {
+    pub reward_name: String,
+
+    pub rarity: Option<String>,
+
+    #[serde(default)]
+    pub tier: i64,
+
+    #[serde(default = "default_count")]
+    pub item_count: u32,
+
+    pub probability: Option<f64>,
+}
@@ -1,9 +1,8 @@
-use serde::Serialize;
-use serde_repr::Deserialize_repr;
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::{core::Resolve, target_types::faction::Faction};
 
-#[derive(Debug, Deserialize_repr, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
+#[derive(Debug, Deserialize_repr, Serialize_repr, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
 #[repr(u8)]
 pub enum ManifestFaction {
     Grineer = 0,
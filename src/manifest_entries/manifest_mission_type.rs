@@ -1,9 +1,8 @@
-use serde::Serialize;
-use serde_repr::Deserialize_repr;
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::{core::Resolve, target_types::mission_type::MissionType};
 
-#[derive(Serialize, Deserialize_repr, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Serialize_repr, Deserialize_repr, Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[repr(u8)]
 pub enum ManifestMissionType {
     Assassination = 0,
@@ -14,14 +13,11 @@ pub enum ManifestMissionType {
     Capture = 5,
     Spy = 7,
     Defense = 8,
-    #[serde(rename(serialize = "Mobile Defense"))]
     MobileDefense = 9,
     Interception = 13,
     Hijack = 14,
-    #[serde(rename(serialize = "Hive Sabotage"))]
     HiveSabotage = 15,
     Excavation = 17,
-    #[serde(rename(serialize = "Infested Salvage"))]
     InfestedSalvage = 21,
     Rathuum = 22,
     Pursuit = 24,
@@ -32,24 +28,17 @@ pub enum ManifestMissionType {
     Landscape = 28,
     Circuit = 31,
     Disruption = 32,
-    #[serde(rename(serialize = "Void Flood"))]
     VoidFlood = 33,
-    #[serde(rename(serialize = "Void Cascade"))]
     VoidCascade = 34,
-    #[serde(rename(serialize = "Void Armaggedon"))]
     VoidArmaggedon = 35,
-    #[serde(rename(serialize = "Void Armaggedon 2"))]
     VoidArmageddon2 = 36,
     Alchemy = 38,
-    #[serde(rename(serialize = "Legacyte Harvest"))]
     LegacyteHarvest = 40,
-    #[serde(rename(serialize = "Shrine Defense"))]
     ShrineDefense = 41,
     Faceoff = 42,
     Descendia = 43,
     /// The Missions from the Perita Rebellion
     Recall = 44,
-    #[serde(rename(serialize = "Follie's Hunt"))]
     FolliesHunt = 45,
 
     // Manual Data Points for mapping in manual assets for CrewBattleNodes
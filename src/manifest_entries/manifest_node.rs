@@ -1,5 +1,4 @@
-use serde::Serialize;
-use serde_repr::Deserialize_repr;
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::{
     core::Resolve,
@@ -10,7 +9,7 @@ use crate::{
     target_types::node::Node,
 };
 
-#[derive(Deserialize_repr, Serialize, PartialEq, Eq, Debug, Copy, Clone)]
+#[derive(Deserialize_repr, Serialize_repr, PartialEq, Eq, Debug, Copy, Clone)]
 #[repr(u8)]
 pub enum NodeType {
     Default = 0,
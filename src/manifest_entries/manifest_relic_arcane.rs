@@ -2,7 +2,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     core::{InternalPath, Resolve, resolve_with},
-    target_types::relic::{Relic, RelicReward, RelicRewardRarity},
+    target_types::{
+        arcane,
+        relic::{Relic, RelicReward, RelicRewardRarity},
+    },
 };
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
@@ -31,6 +34,25 @@ pub struct Arcane {
     pub rarity: ArcaneRarity,
 }
 
+impl Resolve<()> for Arcane {
+    type Output = arcane::Arcane;
+
+    fn resolve(self, _ctx: ()) -> Self::Output {
+        arcane::Arcane {
+            unique_name: self.unique_name,
+            name: self.name,
+            codex_secret: self.codex_secret,
+            exclude_from_codex: self.exclude_from_codex,
+            rarity: self.rarity.resolve(()),
+            rank_stats: self
+                .level_stats
+                .into_iter()
+                .map(|level| level.stats)
+                .collect(),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 pub struct LevelStat {
     pub stats: Vec<String>,
@@ -49,6 +71,19 @@ pub enum ArcaneRarity {
     Common,
 }
 
+impl Resolve<()> for ArcaneRarity {
+    type Output = arcane::ArcaneRarity;
+
+    fn resolve(self, _ctx: ()) -> Self::Output {
+        match self {
+            ArcaneRarity::Legendary => arcane::ArcaneRarity::Legendary,
+            ArcaneRarity::Rare => arcane::ArcaneRarity::Rare,
+            ArcaneRarity::Uncommon => arcane::ArcaneRarity::Uncommon,
+            ArcaneRarity::Common => arcane::ArcaneRarity::Common,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ManifestRelic {
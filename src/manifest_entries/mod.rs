@@ -1,4 +1,5 @@
 pub mod customs_entry;
+pub mod manifest_catalogue;
 pub mod manifest_custom;
 pub mod manifest_faction;
 pub mod manifest_mission_type;
@@ -0,0 +1,130 @@
+//! The write order of the cached export manifests.
+//!
+//! The manifest cache keeps several versions of every export as `<export name>!<hash>.json`. The
+//! file names say nothing about which version is the newest, and mtimes don't survive every copy,
+//! so the cache records the order it wrote them in [`VERSIONS_FILE`], next to the exports.
+
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+/// File name of the version list inside the cache directory.
+pub const VERSIONS_FILE: &str = "versions.json";
+
+/// The cached versions of every export, keyed by export name (e.g. `ExportRegions_en.json`).
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ManifestVersions {
+    /// File names of the cached versions, newest first.
+    exports: BTreeMap<String, Vec<String>>,
+}
+
+impl ManifestVersions {
+    /// Reads the version list of `dir`. An empty list if there is none yet.
+    pub fn read(dir: &Path) -> io::Result<Self> {
+        match fs::read(dir.join(VERSIONS_FILE)) {
+            Ok(bytes) => Self::from_slice(&bytes),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn from_slice(bytes: &[u8]) -> io::Result<Self> {
+        serde_json::from_slice(bytes).map_err(io::Error::other)
+    }
+
+    pub fn to_vec(&self) -> Vec<u8> {
+        serde_json::to_vec_pretty(self).expect("ManifestVersions is always serializable")
+    }
+
+    /// Records `file_name` as the newest version of `export_name` and keeps at most `retain`
+    /// versions. Returns the file names that fell out of the list.
+    pub fn push(&mut self, export_name: &str, file_name: &str, retain: usize) -> Vec<String> {
+        let versions = self.exports.entry(export_name.to_owned()).or_default();
+
+        versions.retain(|version| version != file_name);
+        versions.insert(0, file_name.to_owned());
+
+        versions.split_off(retain.max(1).min(versions.len()))
+    }
+
+    /// The file name of the newest version of `export_name`.
+    pub fn newest(&self, export_name: &str) -> Option<&str> {
+        self.exports
+            .get(export_name)
+            .and_then(|versions| versions.first())
+            .map(String::as_str)
+    }
+
+    /// Whether `file_name` is a cached version of an export, but not its newest one.
+    pub fn is_outdated(&self, file_name: &str) -> bool {
+        file_name
+            .split_once('!')
+            .and_then(|(export_name, _)| self.newest(export_name))
+            .is_some_and(|newest| newest != file_name)
+    }
+
+    /// The same list, with only the newest version of every export.
+    pub fn newest_only(&self) -> Self {
+        Self {
+            exports: self
+                .exports
+                .iter()
+                .map(|(export_name, versions)| {
+                    (
+                        export_name.clone(),
+                        versions.iter().take(1).cloned().collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::manifest_versions::ManifestVersions;
+
+    const REGIONS: &str = "ExportRegions_en.json";
+
+    fn version(hash: &str) -> String {
+        format!("{REGIONS}!{hash}.json")
+    }
+
+    #[test]
+    fn test_push() {
+        let mut versions = ManifestVersions::default();
+
+        assert!(versions.push(REGIONS, &version("a"), 2).is_empty());
+        assert!(versions.push(REGIONS, &version("b"), 2).is_empty());
+        assert!(versions.push(REGIONS, &version("b"), 2).is_empty());
+        assert_eq!(versions.push(REGIONS, &version("c"), 2), [version("a")]);
+        assert!(
+            versions
+                .push("ExportCustoms_en.json", "ExportCustoms_en.json!d.json", 2)
+                .is_empty()
+        );
+
+        let versions = ManifestVersions::from_slice(&versions.to_vec()).unwrap();
+
+        assert_eq!(versions.newest(REGIONS), Some(version("c").as_str()));
+        assert_eq!(versions.newest("ExportRelicArcane_en.json"), None);
+    }
+
+    #[test]
+    fn test_newest_only() {
+        let mut versions = ManifestVersions::default();
+
+        versions.push(REGIONS, &version("a"), 2);
+        versions.push(REGIONS, &version("b"), 2);
+
+        assert!(versions.is_outdated(&version("a")));
+        assert!(!versions.is_outdated(&version("b")));
+        assert!(!versions.is_outdated("ExportRelicArcane_en.json!a.json"));
+        assert!(!versions.is_outdated("index_en.txt.lzma"));
+
+        let mut newest_only = versions.newest_only();
+
+        assert_eq!(newest_only.newest(REGIONS), Some(version("b").as_str()));
+        assert!(newest_only.push(REGIONS, &version("c"), 2).is_empty());
+    }
+}
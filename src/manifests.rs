@@ -1,6 +1,17 @@
 use std::{collections::HashMap, str::FromStr};
 
 use crate::manifest_entries::{
+    manifest_catalogue::{
+        ManifestDrone,
+        ManifestFusionBundle,
+        ManifestItem,
+        ManifestRecipe,
+        ManifestSentinel,
+        ManifestSortieReward,
+        ManifestUpgrade,
+        ManifestWarframe,
+        ManifestWeapon,
+    },
     manifest_custom::ManifestCustom,
     manifest_node::ManifestNode,
     manifest_relic_arcane::RelicArcane,
@@ -76,14 +87,37 @@ impl FromStr for PublicExportIndex {
 }
 
 all_the_exports! {
-    struct ExportRegions(ManifestNode);
-    struct ExportRelicArcane(RelicArcane);
-    struct ExportCustoms(ManifestCustom);
+    pub struct Exports {
+        struct ExportRegions(ManifestNode);
+        struct ExportRelicArcane(RelicArcane);
+        struct ExportCustoms(ManifestCustom);
+    }
+}
+
+all_the_exports! {
+    /// The exports that are only loaded for the [`Catalogue`](crate::catalogue::Catalogue).
+    pub struct CatalogueExports {
+        struct ExportWarframes(ManifestWarframe);
+        struct ExportWeapons(ManifestWeapon);
+        struct ExportResources(ManifestItem);
+        struct ExportUpgrades(ManifestUpgrade);
+        struct ExportRecipes(ManifestRecipe);
+        struct ExportSentinels(ManifestSentinel);
+        struct ExportKeys(ManifestItem);
+        struct ExportGear(ManifestItem);
+        struct ExportDrones(ManifestDrone);
+        struct ExportFlavour(ManifestItem);
+        struct ExportFusionBundles(ManifestFusionBundle);
+        struct ExportSortieRewards(ManifestSortieReward);
+    }
 }
 
 macro_rules! all_the_exports {
     (
-        $( struct $ident:ident( $inner_type:ty ); )*
+        $( #[$meta:meta] )*
+        pub struct $exports:ident {
+            $( struct $ident:ident( $inner_type:ty ); )*
+        }
     ) => {
         pastey::paste! {
             $(
@@ -94,8 +128,9 @@ macro_rules! all_the_exports {
                 }
             )*
 
+            $( #[$meta] )*
             #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
-            pub struct Exports {
+            pub struct $exports {
                 $(
                     pub [<$ident:snake>]: $ident,
                 )*
@@ -0,0 +1,377 @@
+//! Offline data bundles.
+//!
+//! A bundle is a single tar archive with everything the `DataFetcher` and the
+//! `DefaultContextProvider` download: the WFCD data, the drops, the assets (including their
+//! lockfiles), the export manifests and the export index. Prepare it on a host with internet access
+//! with [`export_bundle`] and lay it out on the target host with [`import_bundle`].
+//!
+//! The first entry of the archive is always [`MANIFEST_FILE`], a [`BundleManifest`] with the hash
+//! of every other entry. Importing fails if any entry is missing, unknown or doesn't match its
+//! hash.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Read, Write},
+    path::{Component, Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bundle_lock::{is_local_state, lock_path},
+    context_snapshot::sha256_hex,
+    local_context_provider::PathContext,
+    manifest_versions::{ManifestVersions, VERSIONS_FILE},
+};
+
+/// Bumped whenever the layout of the archive changes in an incompatible way.
+pub const BUNDLE_FORMAT_VERSION: u32 = 2;
+
+/// Name of the manifest entry in the archive.
+pub const MANIFEST_FILE: &str = "bundle.json";
+
+#[derive(Debug, thiserror::Error)]
+pub enum BundleError {
+    #[error(transparent)]
+    Io(#[from] io::Error),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error("Unsupported bundle format version {found} (expected {expected})")]
+    UnsupportedVersion { found: u32, expected: u32 },
+
+    #[error("The archive does not start with {MANIFEST_FILE}")]
+    MissingManifest,
+
+    #[error("{0} is not part of the bundle manifest")]
+    UnexpectedEntry(String),
+
+    #[error("{0} does not match the bundle manifest")]
+    HashMismatch(String),
+
+    #[error("{0} is listed in the bundle manifest, but missing from the archive")]
+    MissingEntry(String),
+}
+
+/// The directories a bundle is exported from or imported into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BundleDirs<'a> {
+    pub paths: PathContext<'a>,
+
+    /// The directory of the manifest cache, holding the `Export*_en.json` manifests and the
+    /// export index.
+    pub exports_dir: &'a Path,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleManifest {
+    /// Always [`BUNDLE_FORMAT_VERSION`] for bundles written by this version of the crate.
+    pub format_version: u32,
+
+    pub created_at: DateTime<Utc>,
+
+    /// Hex encoded SHA-256 of every entry, keyed by its path in the archive
+    /// (e.g. `data/languages.json`).
+    pub files: BTreeMap<String, String>,
+}
+
+/// The sections of a bundle. Every section maps to one directory of [`BundleDirs`].
+const SECTIONS: [&str; 4] = ["data", "drops", "assets", "exports"];
+
+impl BundleDirs<'_> {
+    fn section_dir(&self, section: &str) -> Option<&Path> {
+        match section {
+            "data" => Some(self.paths.data_dir),
+            "drops" => Some(self.paths.drops_dir),
+            "assets" => Some(self.paths.assets_dir),
+            "exports" => Some(self.exports_dir),
+            _ => None,
+        }
+    }
+
+    /// Maps a path in the archive to the path on disk.
+    ///
+    /// `data/languages.json` is placed in the data dir, `data.lock.json` next to it.
+    fn resolve(&self, entry: &str) -> Option<PathBuf> {
+        if let Some((section, file)) = entry.split_once('/') {
+            let dir = self.section_dir(section)?;
+            let is_plain_file = matches!(
+                Path::new(file).components().collect::<Vec<_>>()[..],
+                [Component::Normal(_)]
+            );
+
+            return is_plain_file.then(|| dir.join(file));
+        }
+
+        let section = entry.strip_suffix(".lock.json")?;
+
+        lock_path(self.section_dir(section)?).ok()
+    }
+}
+
+/// Collects every file of `dirs`, keyed by its path in the archive.
+///
+/// Of the export manifests, only the newest cached version of each export is bundled, together
+/// with a [`VERSIONS_FILE`] listing just those. The imported cache always resolves to the same
+/// versions, no matter what else it holds.
+fn collect_files(dirs: &BundleDirs<'_>) -> io::Result<BTreeMap<String, Vec<u8>>> {
+    let mut files = BTreeMap::new();
+    let versions = ManifestVersions::read(dirs.exports_dir)?;
+
+    files.insert(
+        format!("exports/{VERSIONS_FILE}"),
+        versions.newest_only().to_vec(),
+    );
+
+    for section in SECTIONS {
+        let dir = dirs.section_dir(section).expect("every section has a dir");
+
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+
+            if !entry.file_type()?.is_file() {
+                continue;
+            }
+
+            let Some(file_name) = entry.file_name().to_str().map(str::to_owned) else {
+                continue;
+            };
+
+            let is_current = section != "exports"
+                || (file_name != VERSIONS_FILE && !versions.is_outdated(&file_name));
+
+            if !is_local_state(&file_name) && is_current {
+                files.insert(format!("{section}/{file_name}"), fs::read(entry.path())?);
+            }
+        }
+
+        match fs::read(lock_path(dir)?) {
+            Ok(lock) => {
+                files.insert(format!("{section}.lock.json"), lock);
+            },
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {},
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(files)
+}
+
+fn append_entry<W: Write>(
+    builder: &mut tar::Builder<W>,
+    path: &str,
+    contents: &[u8],
+    mtime: u64,
+) -> io::Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(contents.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(mtime);
+
+    builder.append_data(&mut header, path, contents)
+}
+
+/// Writes every file of `dirs` as a bundle to `writer`.
+pub fn export_bundle(
+    dirs: BundleDirs<'_>,
+    writer: impl Write,
+) -> Result<BundleManifest, BundleError> {
+    let files = collect_files(&dirs)?;
+
+    let manifest = BundleManifest {
+        format_version: BUNDLE_FORMAT_VERSION,
+        created_at: Utc::now(),
+        files: files
+            .iter()
+            .map(|(path, contents)| (path.clone(), sha256_hex(contents)))
+            .collect(),
+    };
+
+    let mtime = manifest.created_at.timestamp().max(0) as u64;
+    let mut builder = tar::Builder::new(writer);
+
+    append_entry(
+        &mut builder,
+        MANIFEST_FILE,
+        &serde_json::to_vec_pretty(&manifest)?,
+        mtime,
+    )?;
+
+    for (path, contents) in &files {
+        append_entry(&mut builder, path, contents, mtime)?;
+    }
+
+    builder.into_inner()?.flush()?;
+
+    Ok(manifest)
+}
+
+/// Verifies the bundle read from `reader` and lays it out in `dirs`, creating them if needed.
+///
+/// Every entry is checked before it is written, so a corrupted archive never overwrites a file
+/// with bad data. It can still leave a partially imported bundle behind.
+pub fn import_bundle(
+    reader: impl Read,
+    dirs: BundleDirs<'_>,
+) -> Result<BundleManifest, BundleError> {
+    let mut archive = tar::Archive::new(reader);
+    let mut entries = archive.entries()?;
+
+    let manifest = match entries.next() {
+        Some(entry) => {
+            let entry = entry?;
+
+            if entry.path()?.as_os_str() != MANIFEST_FILE {
+                return Err(BundleError::MissingManifest);
+            }
+
+            read_manifest(entry)?
+        },
+        None => return Err(BundleError::MissingManifest),
+    };
+
+    let mut pending = manifest.files.clone();
+
+    for section in SECTIONS {
+        fs::create_dir_all(dirs.section_dir(section).expect("every section has a dir"))?;
+    }
+
+    for entry in entries {
+        let mut entry = entry?;
+        let path = entry.path()?.to_string_lossy().into_owned();
+
+        let (Some(hash), Some(target_path)) = (pending.remove(&path), dirs.resolve(&path)) else {
+            return Err(BundleError::UnexpectedEntry(path));
+        };
+
+        let mut contents = Vec::with_capacity(entry.size() as usize);
+        entry.read_to_end(&mut contents)?;
+
+        if sha256_hex(&contents) != hash {
+            return Err(BundleError::HashMismatch(path));
+        }
+
+        let tmp_path = target_path.with_added_extension("tmp");
+        fs::write(&tmp_path, contents)?;
+        fs::rename(&tmp_path, &target_path)?;
+    }
+
+    if let Some(path) = pending.into_keys().next() {
+        return Err(BundleError::MissingEntry(path));
+    }
+
+    Ok(manifest)
+}
+
+fn read_manifest(mut entry: impl Read) -> Result<BundleManifest, BundleError> {
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Header {
+        format_version: u32,
+    }
+
+    let mut bytes = Vec::new();
+    entry.read_to_end(&mut bytes)?;
+
+    let header: Header = serde_json::from_slice(&bytes)?;
+
+    if header.format_version != BUNDLE_FORMAT_VERSION {
+        return Err(BundleError::UnsupportedVersion {
+            found: header.format_version,
+            expected: BUNDLE_FORMAT_VERSION,
+        });
+    }
+
+    Ok(serde_json::from_slice(&bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use crate::{
+        local_context_provider::PathContext,
+        manifest_versions::{ManifestVersions, VERSIONS_FILE},
+        offline_bundle::{BundleDirs, BundleError, export_bundle, import_bundle},
+    };
+
+    fn dirs([data_dir, drops_dir, assets_dir, exports_dir]: &[PathBuf; 4]) -> BundleDirs<'_> {
+        BundleDirs {
+            paths: PathContext {
+                data_dir,
+                drops_dir,
+                assets_dir,
+            },
+            exports_dir,
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let root = std::env::temp_dir().join(format!("offline_bundle_{}", std::process::id()));
+        let [source, target] = ["source", "target"]
+            .map(|side| ["data", "drops", "assets", "cache"].map(|dir| root.join(side).join(dir)));
+
+        for dir in &source {
+            fs::create_dir_all(dir).unwrap();
+        }
+
+        fs::write(source[0].join("languages.json"), "{}").unwrap();
+        fs::write(root.join("source/data.lock.json"), "{}").unwrap();
+        fs::write(source[1].join("data.json"), "[]").unwrap();
+        fs::write(source[1].join(".validators.json"), "{}").unwrap();
+        fs::write(source[3].join("index_en.txt.lzma"), [0u8, 1, 2]).unwrap();
+
+        let mut versions = ManifestVersions::default();
+
+        for version in [
+            "ExportRegions_en.json!a.json",
+            "ExportRegions_en.json!b.json",
+        ] {
+            fs::write(source[3].join(version), version).unwrap();
+            versions.push("ExportRegions_en.json", version, 2);
+        }
+
+        fs::write(source[3].join(VERSIONS_FILE), versions.to_vec()).unwrap();
+
+        // An older version that is still lying around in the target cache
+        fs::create_dir_all(&target[3]).unwrap();
+        fs::write(target[3].join("ExportRegions_en.json!c.json"), "c").unwrap();
+
+        let mut archive = Vec::new();
+        let manifest = export_bundle(dirs(&source), &mut archive).unwrap();
+
+        assert_eq!(manifest.files.len(), 6);
+        assert!(
+            !manifest
+                .files
+                .contains_key("exports/ExportRegions_en.json!a.json")
+        );
+
+        import_bundle(archive.as_slice(), dirs(&target)).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(target[0].join("languages.json")).unwrap(),
+            "{}"
+        );
+        assert!(root.join("target/data.lock.json").exists());
+        assert!(!target[1].join(".validators.json").exists());
+        assert_eq!(
+            ManifestVersions::read(&target[3])
+                .unwrap()
+                .newest("ExportRegions_en.json"),
+            Some("ExportRegions_en.json!b.json")
+        );
+
+        let tampered = String::from_utf8_lossy(&archive).replace("[]", "{}");
+        let result = import_bundle(tampered.as_bytes(), dirs(&target));
+
+        assert!(matches!(result, Err(BundleError::HashMismatch(_))));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,230 @@
+//! Python bindings.
+//!
+//! Exposes loading the [`Context`], parsing the worldstate and the cycle calculators as the
+//! `worldstate_parser` Python module. Parsed values are returned as plain dicts, so they can be
+//! fed straight into pandas and friends. Build the module with `maturin develop`, which picks up
+//! the features from `pyproject.toml`.
+//!
+//! The tests embed an interpreter, so they need the Python shared library:
+//! `cargo test --features python`.
+
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use pyo3::{exceptions::PyValueError, prelude::*};
+use serde::Serialize;
+
+use crate::{
+    BlockingContextProvider,
+    WorldState,
+    WorldstateError,
+    context_snapshot::{ContextSnapshot, SnapshotError},
+    core::Context,
+    cycles::{
+        cambion_drift::CambionDriftCycle,
+        cetus::CetusCycle,
+        duviri::DuviriCycle,
+        orb_vallis::OrbVallisCycle,
+    },
+    local_context_provider::{LocalContextProvider, PathContext, WorldstateDataError},
+};
+
+impl From<WorldstateDataError> for PyErr {
+    fn from(err: WorldstateDataError) -> Self {
+        match err {
+            WorldstateDataError::Io(err) => err.into(),
+            err => PyValueError::new_err(err.to_string()),
+        }
+    }
+}
+
+impl From<SnapshotError> for PyErr {
+    fn from(err: SnapshotError) -> Self {
+        match err {
+            SnapshotError::Io(err) => err.into(),
+            err => PyValueError::new_err(err.to_string()),
+        }
+    }
+}
+
+impl From<WorldstateError> for PyErr {
+    fn from(err: WorldstateError) -> Self {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
+/// A loaded [`Context`], kept on the Rust side so it's only built once.
+#[pyclass(name = "Context", frozen)]
+pub struct PyContext(Context);
+
+#[pymethods]
+impl PyContext {
+    /// Builds the context from the directories the data fetcher writes.
+    #[staticmethod]
+    fn from_dirs(
+        data_dir: PathBuf,
+        drops_dir: PathBuf,
+        assets_dir: PathBuf,
+        exports_dir: PathBuf,
+    ) -> PyResult<Self> {
+        let provider = LocalContextProvider {
+            paths: PathContext {
+                data_dir: &data_dir,
+                drops_dir: &drops_dir,
+                assets_dir: &assets_dir,
+            },
+            exports_dir: &exports_dir,
+        };
+
+        Ok(Self(provider.get_ctx_blocking()?))
+    }
+
+    /// Loads the context from a context snapshot file.
+    #[staticmethod]
+    fn from_snapshot(path: PathBuf) -> PyResult<Self> {
+        Ok(Self(ContextSnapshot::read_from_file(path)?.into_context()))
+    }
+}
+
+/// Converts `value` into plain Python objects by round-tripping it through JSON.
+fn to_python(py: Python<'_>, value: &impl Serialize) -> PyResult<Py<PyAny>> {
+    let json =
+        serde_json::to_string(value).map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+    Ok(py.import("json")?.call_method1("loads", (json,))?.unbind())
+}
+
+/// Parses the raw worldstate and returns the parsed [`WorldState`] as a dict.
+#[pyfunction]
+fn parse_worldstate(py: Python<'_>, worldstate: &str, ctx: &PyContext) -> PyResult<Py<PyAny>> {
+    let world_state = py.detach(|| WorldState::parse(worldstate, &ctx.0))?;
+
+    to_python(py, &world_state)
+}
+
+/// The Cetus cycle at `time` (a timezone aware datetime), or now.
+#[pyfunction]
+#[pyo3(signature = (time = None))]
+fn cetus_cycle(py: Python<'_>, time: Option<DateTime<Utc>>) -> PyResult<Py<PyAny>> {
+    to_python(py, &time.map_or_else(CetusCycle::now, CetusCycle::at))
+}
+
+/// The Cambion Drift cycle at `time` (a timezone aware datetime), or now.
+#[pyfunction]
+#[pyo3(signature = (time = None))]
+fn cambion_drift_cycle(py: Python<'_>, time: Option<DateTime<Utc>>) -> PyResult<Py<PyAny>> {
+    to_python(
+        py,
+        &time.map_or_else(CambionDriftCycle::now, CambionDriftCycle::at),
+    )
+}
+
+/// The Orb Vallis cycle at `time` (a timezone aware datetime), or now.
+#[pyfunction]
+#[pyo3(signature = (time = None))]
+fn orb_vallis_cycle(py: Python<'_>, time: Option<DateTime<Utc>>) -> PyResult<Py<PyAny>> {
+    to_python(
+        py,
+        &time.map_or_else(OrbVallisCycle::now, OrbVallisCycle::at),
+    )
+}
+
+/// The Duviri cycle at `time` (a timezone aware datetime), or now.
+#[pyfunction]
+#[pyo3(signature = (time = None))]
+fn duviri_cycle(py: Python<'_>, time: Option<DateTime<Utc>>) -> PyResult<Py<PyAny>> {
+    to_python(py, &time.map_or_else(DuviriCycle::now, DuviriCycle::at))
+}
+
+#[pymodule]
+fn worldstate_parser(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyContext>()?;
+    module.add_function(wrap_pyfunction!(parse_worldstate, module)?)?;
+    module.add_function(wrap_pyfunction!(cetus_cycle, module)?)?;
+    module.add_function(wrap_pyfunction!(cambion_drift_cycle, module)?)?;
+    module.add_function(wrap_pyfunction!(orb_vallis_cycle, module)?)?;
+    module.add_function(wrap_pyfunction!(duviri_cycle, module)?)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use pyo3::{
+        exceptions::{PyOSError, PyValueError},
+        prelude::*,
+        types::PyDict,
+    };
+
+    use crate::{
+        context_snapshot::ContextSnapshot,
+        context_sources::tests::fixture_sources,
+        python::{PyContext, cetus_cycle, parse_worldstate},
+        worldstate::tests::RAW,
+    };
+
+    #[test]
+    fn test_parse_worldstate() {
+        let path = std::env::temp_dir().join(format!("py_snapshot_{}", std::process::id()));
+
+        ContextSnapshot::from_sources(&fixture_sources())
+            .unwrap()
+            .write_to_file(&path)
+            .unwrap();
+
+        let ctx = PyContext::from_snapshot(path.clone()).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        Python::initialize();
+        Python::attach(|py| {
+            let parsed = parse_worldstate(py, RAW, &ctx).unwrap();
+            let parsed = parsed.bind(py).cast::<PyDict>().unwrap();
+
+            let season: u32 = parsed
+                .get_item("nightwave")
+                .unwrap()
+                .unwrap()
+                .get_item("season")
+                .unwrap()
+                .extract()
+                .unwrap();
+
+            assert_eq!(season, 13);
+            assert!(
+                parse_worldstate(py, "{}", &ctx)
+                    .unwrap_err()
+                    .is_instance_of::<PyValueError>(py)
+            );
+        });
+    }
+
+    #[test]
+    fn test_from_dirs() {
+        let missing = std::env::temp_dir().join(format!("py_missing_{}", std::process::id()));
+
+        let Err(err) =
+            PyContext::from_dirs(missing.clone(), missing.clone(), missing.clone(), missing)
+        else {
+            panic!("the directories don't exist");
+        };
+
+        Python::initialize();
+        Python::attach(|py| assert!(err.is_instance_of::<PyOSError>(py)));
+    }
+
+    #[test]
+    fn test_cycles() {
+        Python::initialize();
+        Python::attach(|py| {
+            // 2026-02-04T16:09:00Z, the start of a known night
+            let time = "2026-02-04T16:09:00Z".parse::<DateTime<Utc>>().unwrap();
+
+            let cycle = cetus_cycle(py, Some(time)).unwrap();
+            let state: String = cycle.bind(py).get_item("state").unwrap().extract().unwrap();
+
+            assert_eq!(state, "Night");
+            assert!(cetus_cycle(py, None).is_ok());
+        });
+    }
+}
@@ -0,0 +1,324 @@
+//! Lookups over the relics of `ExportRelicArcane`.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::{
+    core::{Context, ContextRef},
+    target_types::{
+        relic::{Relic, RelicRewardRarity, relic_base_name},
+        worldstate_types::{
+            fissure::{FissureRelic, FissureTier},
+            vault_trader::VaultTrader,
+        },
+    },
+};
+
+/// A relic that drops a specific reward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelicDrop<'a> {
+    pub relic: &'a Relic,
+    pub rarity: &'a RelicRewardRarity,
+}
+
+/// A catalogue of every relic, built from [`Context`] with [`Context::relics`].
+///
+/// Parsing the worldstate builds one as well, to list the relics of every fissure.
+///
+/// Every relic is exported once per refinement. The catalogue keeps one entry per relic (e.g.
+/// `Lith A1`), preferring the Intact one.
+#[derive(Debug, Clone)]
+pub struct RelicCatalogue<'a> {
+    ctx: ContextRef<'a>,
+    relics: BTreeMap<&'a str, &'a Relic>,
+    /// Keyed by the lowercased reward name.
+    by_reward: HashMap<String, Vec<RelicDrop<'a>>>,
+}
+
+impl Context {
+    pub fn relics(&self) -> RelicCatalogue<'_> {
+        RelicCatalogue::new(self.as_ref())
+    }
+}
+
+impl<'a> ContextRef<'a> {
+    pub fn relics(self) -> RelicCatalogue<'a> {
+        RelicCatalogue::new(self)
+    }
+}
+
+impl<'a> RelicCatalogue<'a> {
+    pub fn new(ctx: ContextRef<'a>) -> Self {
+        let mut relics: BTreeMap<&str, &Relic> = BTreeMap::new();
+
+        for relic in ctx.custom_maps.relic_uniq_to_relic.values() {
+            relics
+                .entry(relic.base_name())
+                .and_modify(|current| {
+                    if relic.unique_name.ends_with("Bronze") {
+                        *current = relic;
+                    }
+                })
+                .or_insert(relic);
+        }
+
+        let mut by_reward: HashMap<String, Vec<RelicDrop>> = HashMap::new();
+
+        for relic in relics.values() {
+            for reward in &relic.relic_rewards {
+                by_reward
+                    .entry(reward.reward_name.to_lowercase())
+                    .or_default()
+                    .push(RelicDrop {
+                        relic,
+                        rarity: &reward.rarity,
+                    });
+            }
+        }
+
+        Self {
+            ctx,
+            relics,
+            by_reward,
+        }
+    }
+
+    /// Every relic, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = &'a Relic> + '_ {
+        self.relics.values().copied()
+    }
+
+    /// Looks up a relic by its unique name, in any refinement.
+    pub fn get(&self, unique_name: &str) -> Option<&'a Relic> {
+        self.ctx.custom_maps.relic_uniq_to_relic.get(unique_name)
+    }
+
+    /// Looks up a relic by name, case insensitively. Accepts `Lith A1`, `Lith A1 Relic`,
+    /// `Lith A1 Radiant` and so on.
+    pub fn by_name(&self, name: &str) -> Option<&'a Relic> {
+        let base_name = relic_base_name(name.trim());
+
+        self.relics
+            .iter()
+            .find(|(candidate, _)| candidate.eq_ignore_ascii_case(base_name))
+            .map(|(_, relic)| *relic)
+    }
+
+    /// Every relic that can be opened in a fissure of `tier`.
+    pub fn by_tier(&self, tier: FissureTier) -> impl Iterator<Item = &'a Relic> + '_ {
+        self.iter().filter(move |relic| {
+            relic
+                .tier()
+                .is_some_and(|relic_tier| tier.accepts(relic_tier))
+        })
+    }
+
+    /// Every relic that can be opened in a fissure of `tier`, sorted by name. Unvaulted relics
+    /// come first.
+    pub fn fissure_relics(&self, tier: FissureTier) -> Vec<FissureRelic> {
+        let mut relics: Vec<FissureRelic> = self
+            .by_tier(tier)
+            .filter_map(|relic| {
+                Some(FissureRelic {
+                    name: relic.base_name().to_owned(),
+                    tier: relic.tier()?,
+                    rewards: relic.relic_rewards.clone(),
+                    is_vaulted: self.is_vaulted(relic),
+                })
+            })
+            .collect();
+
+        relics.sort_by(|a, b| {
+            let is_vaulted = |relic: &FissureRelic| relic.is_vaulted == Some(true);
+
+            is_vaulted(a)
+                .cmp(&is_vaulted(b))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+
+        relics
+    }
+
+    /// The relics that drop `reward_name` (e.g. `Akstiletto Prime Barrel`), case insensitively.
+    pub fn dropping(&self, reward_name: &str) -> &[RelicDrop<'a>] {
+        self.by_reward
+            .get(&reward_name.to_lowercase())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Whether the relic no longer drops from missions or bounties. `None` if the drop tables
+    /// weren't loaded.
+    pub fn is_vaulted(&self, relic: &Relic) -> Option<bool> {
+        self.ctx
+            .worldstate_data
+            .unvaulted_relics
+            .as_ref()
+            .map(|unvaulted| !unvaulted.contains(relic.base_name()))
+    }
+
+    /// Whether the relic is currently sold by the vault trader.
+    pub fn is_sold_by(&self, relic: &Relic, vault_trader: &VaultTrader) -> bool {
+        vault_trader
+            .shop
+            .iter()
+            .any(|item| relic_base_name(&item.item_type) == relic.base_name())
+    }
+
+    /// Every relic currently sold by the vault trader.
+    pub fn sold_by(&self, vault_trader: &'a VaultTrader) -> impl Iterator<Item = &'a Relic> + '_ {
+        self.iter()
+            .filter(move |relic| self.is_sold_by(relic, vault_trader))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        context_sources::tests::fixture_sources,
+        target_types::{
+            relic::RelicRewardRarity,
+            worldstate_types::{
+                fissure::FissureTier,
+                vault_trader::{
+                    VaultTrader,
+                    VaultTraderCurrency,
+                    VaultTraderManifest,
+                    VaultTraderPrice,
+                },
+            },
+        },
+    };
+
+    #[test]
+    fn test_catalogue() {
+        let mut sources = fixture_sources();
+        sources.exports.relic_arcane = br#"{ "ExportRelicArcane": [{
+            "uniqueName": "/Lotus/Types/Game/Projections/T1VoidProjectionA1Bronze",
+            "name": "Lith A1 Intact",
+            "codexSecret": false,
+            "description": "A relic",
+            "relicRewards": [{
+                "rewardName": "/Lotus/StoreItems/Types/Recipes/Weapons/WeaponParts/AkstilettoPrimeBarrel",
+                "rarity": "COMMON",
+                "tier": 0,
+                "itemCount": 1
+            }]
+        }] }"#
+            .to_vec();
+
+        let ctx = sources.build().unwrap();
+        let relics = ctx.relics();
+
+        let relic = relics.by_name("lith a1 relic").unwrap();
+
+        assert_eq!(relics.by_tier(FissureTier::Lith).count(), 1);
+        assert_eq!(relics.by_tier(FissureTier::Axi).count(), 0);
+
+        // `/Lotus/StoreItems/Types/Recipes/Weapons/WeaponParts/AkstilettoPrimeBarrel`
+        assert_eq!(
+            relic.relic_rewards[0].reward_name,
+            "Akstiletto Prime Barrel"
+        );
+
+        let drops = relics.dropping("Akstiletto Prime Barrel");
+
+        assert_eq!(drops.len(), 1);
+        assert_eq!(drops[0].relic.base_name(), "Lith A1");
+        assert_eq!(relics.dropping("akstiletto prime barrel").len(), 1);
+        assert!(relics.dropping("Akstiletto Prime").is_empty());
+        assert_eq!(drops[0].rarity, &RelicRewardRarity::Common);
+        assert_eq!(relics.is_vaulted(relic), None);
+
+        let vault_trader = VaultTrader {
+            id: String::new(),
+            activation: Default::default(),
+            expiry: Default::default(),
+            initial_start_date: Default::default(),
+            node: String::new(),
+            shop: vec![VaultTraderManifest {
+                item_type: "Lith A1 Relic".to_owned(),
+                price: VaultTraderPrice {
+                    currency: VaultTraderCurrency::Aya,
+                    amount: 1,
+                },
+            }],
+            twitch_prime_shop: Vec::new(),
+            schedule_info: Vec::new(),
+        };
+
+        assert!(relics.is_sold_by(relic, &vault_trader));
+    }
+
+    #[test]
+    fn test_fissure_relics() {
+        let relic = |name: &str| {
+            format!(
+                r#"{{
+                    "uniqueName": "/Lotus/Types/Game/Projections/{}",
+                    "name": "{name} Intact",
+                    "codexSecret": false,
+                    "description": "A relic",
+                    "relicRewards": []
+                }}"#,
+                name.replace(' ', "")
+            )
+        };
+
+        let mut sources = fixture_sources();
+        sources.exports.relic_arcane = format!(
+            r#"{{ "ExportRelicArcane": [{}, {}, {}] }}"#,
+            relic("Meso C1"),
+            relic("Lith B1"),
+            relic("Lith A1")
+        )
+        .into_bytes();
+        sources.worldstate_data.drops = br#"{
+            "cetusBountyRewards": [],
+            "solarisBountyRewards": [],
+            "deimosRewards": [],
+            "zarimanRewards": [],
+            "entratiLabRewards": [],
+            "hexRewards": [],
+            "sortieRewards": [],
+            "missionRewards": {
+                "Mercury": {
+                    "Apollodorus": {
+                        "rewards": { "A": [
+                            { "itemName": "Meso C1 Relic", "rarity": "Common", "chance": 20 },
+                            { "itemName": "Lith B1 Relic", "rarity": "Common", "chance": 20 }
+                        ] }
+                    }
+                }
+            }
+        }"#
+        .to_vec();
+
+        let ctx = sources.build().unwrap();
+        let relics = ctx.relics();
+
+        let names = |tier| {
+            relics
+                .fissure_relics(tier)
+                .into_iter()
+                .map(|relic| (relic.name, relic.is_vaulted))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            names(FissureTier::Omnia),
+            [
+                ("Lith B1".to_owned(), Some(false)),
+                ("Meso C1".to_owned(), Some(false)),
+                ("Lith A1".to_owned(), Some(true)),
+            ]
+        );
+        assert_eq!(
+            names(FissureTier::Lith),
+            [
+                ("Lith B1".to_owned(), Some(false)),
+                ("Lith A1".to_owned(), Some(true)),
+            ]
+        );
+        assert!(names(FissureTier::Axi).is_empty());
+    }
+}
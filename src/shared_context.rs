@@ -0,0 +1,110 @@
+//! A [`Context`] that is built once and shared between concurrent parses.
+
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::{BlockingContextProvider, ContextProvider, WorldState, WorldstateError, core::Context};
+
+/// A shared, hot-swappable [`Context`].
+///
+/// Cloning is cheap, all clones see the same context. Parses that are already running keep the
+/// context they started with, while [`SharedContext::swap`] or one of the reload methods replaces
+/// it for every parse started afterwards.
+///
+/// ```no_run
+/// # use worldstate_parser::{
+/// #     BlockingContextProvider,
+/// #     context_sources::{ContextSources, InMemoryContextProvider},
+/// #     shared_context::SharedContext,
+/// # };
+/// # let (sources, new_sources) = (ContextSources::default(), ContextSources::default());
+/// # let raw_worldstate = "";
+/// let ctx = SharedContext::new(InMemoryContextProvider(sources).get_ctx_blocking()?);
+///
+/// // In every request handler
+/// let world_state = ctx.parse(raw_worldstate)?;
+///
+/// // When new assets arrive
+/// ctx.reload_blocking(InMemoryContextProvider(new_sources))?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct SharedContext(Arc<RwLock<Arc<Context>>>);
+
+impl SharedContext {
+    pub fn new(ctx: Context) -> Self {
+        Self(Arc::new(RwLock::new(Arc::new(ctx))))
+    }
+
+    /// Returns the current context.
+    pub fn load(&self) -> Arc<Context> {
+        self.0
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Replaces the context and returns the previous one.
+    pub fn swap(&self, ctx: Context) -> Arc<Context> {
+        let mut current = self.0.write().unwrap_or_else(PoisonError::into_inner);
+
+        std::mem::replace(&mut *current, Arc::new(ctx))
+    }
+
+    /// Rebuilds the context with `provider` and swaps it in.
+    ///
+    /// If the provider fails, the current context stays in place.
+    pub async fn reload<P: ContextProvider>(&self, provider: P) -> Result<Arc<Context>, P::Err> {
+        Ok(self.swap(provider.get_ctx().await?))
+    }
+
+    /// Like [`SharedContext::reload`], for a [`BlockingContextProvider`].
+    pub fn reload_blocking<P: BlockingContextProvider>(
+        &self,
+        provider: P,
+    ) -> Result<Arc<Context>, P::Err> {
+        Ok(self.swap(provider.get_ctx_blocking()?))
+    }
+
+    /// Parses the raw worldstate with the current context.
+    pub fn parse(&self, s: &str) -> Result<WorldState, WorldstateError> {
+        WorldState::parse(s, &self.load())
+    }
+}
+
+impl From<Context> for SharedContext {
+    fn from(ctx: Context) -> Self {
+        Self::new(ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        context_sources::{InMemoryContextProvider, tests::fixture_sources},
+        shared_context::SharedContext,
+    };
+
+    #[test]
+    fn test_swap() {
+        let mut sources = fixture_sources();
+        sources.worldstate_data.sol_nodes =
+            br#"{ "MercuryHUB": { "value": "Larunda Relay (Mercury)" } }"#.to_vec();
+
+        let shared = SharedContext::new(sources.build().unwrap());
+        let clone = shared.clone();
+        let before = shared.load();
+
+        sources.worldstate_data.sol_nodes =
+            br#"{ "VenusHUB": { "value": "Kronia Relay (Venus)" } }"#.to_vec();
+
+        let previous = clone
+            .reload_blocking(InMemoryContextProvider(sources))
+            .unwrap();
+
+        assert!(Arc::ptr_eq(&before, &previous));
+        assert!(shared.load().worldstate_data.hubs.contains_key("VenusHUB"));
+        assert!(before.worldstate_data.hubs.contains_key("MercuryHUB"));
+    }
+}
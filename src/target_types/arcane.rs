@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Arcane {
+    pub unique_name: String,
+
+    pub name: String,
+
+    pub codex_secret: bool,
+
+    pub exclude_from_codex: bool,
+
+    pub rarity: ArcaneRarity,
+
+    /// The stat lines at each rank, starting at rank 0.
+    pub rank_stats: Vec<Vec<String>>,
+}
+
+impl Arcane {
+    /// The highest rank, `None` if the export has no stats for this arcane.
+    pub fn max_rank(&self) -> Option<usize> {
+        self.rank_stats.len().checked_sub(1)
+    }
+
+    /// The stat lines at `rank`.
+    pub fn stats_at(&self, rank: usize) -> Option<&[String]> {
+        self.rank_stats.get(rank).map(Vec::as_slice)
+    }
+}
+
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum ArcaneRarity {
+    Common,
+
+    Uncommon,
+
+    Rare,
+
+    Legendary,
+}
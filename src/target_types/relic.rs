@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::target_types::worldstate_types::fissure::FissureTier;
+
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -15,8 +17,38 @@ pub struct Relic {
     pub relic_rewards: Vec<RelicReward>,
 }
 
+/// `Lith A1 Intact` -> `Lith A1`
+pub fn relic_base_name(name: &str) -> &str {
+    match name.match_indices(' ').nth(1) {
+        Some((index, _)) => &name[..index],
+        None => name,
+    }
+}
+
+impl Relic {
+    /// The name without the refinement, e.g. `Lith A1` for `Lith A1 Intact`.
+    pub fn base_name(&self) -> &str {
+        relic_base_name(&self.name)
+    }
+
+    /// The fissure tier this relic is opened in. Never [`FissureTier::Omnia`].
+    pub fn tier(&self) -> Option<FissureTier> {
+        let tier = self.name.split(' ').next()?;
+
+        [
+            FissureTier::Lith,
+            FissureTier::Meso,
+            FissureTier::Neo,
+            FissureTier::Axi,
+            FissureTier::Requiem,
+        ]
+        .into_iter()
+        .find(|candidate| tier.eq_ignore_ascii_case(&candidate.to_string()))
+    }
+}
+
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Hash)]
 #[serde(rename_all = "camelCase")]
 pub struct RelicReward {
     pub reward_name: String,
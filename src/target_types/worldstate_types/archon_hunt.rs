@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    target_types::{mission_type::MissionType, node::Node},
+    target_types::{mission_type::MissionType, node::Node, worldstate_types::sortie::SortieBoss},
     wfcd_data::bounty_rewards::DropItem,
 };
 
@@ -20,7 +20,15 @@ pub struct ArchonHunt {
 
     pub seed: i64,
 
-    pub boss: Option<String>,
+    pub boss: Option<SortieBoss>,
+
+    pub archon: Option<Archon>,
+
+    /// The shard dropped by the final mission, e.g. `Crimson Archon Shard`.
+    pub guaranteed_reward: Option<String>,
+
+    /// The archon of next week's hunt, following the fixed rotation.
+    pub next_archon: Option<Archon>,
 
     pub missions: Vec<ArchonHuntMission>,
 }
@@ -33,3 +41,83 @@ pub struct ArchonHuntMission {
 
     pub node: Option<Node>,
 }
+
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash, derive_more::Display)]
+pub enum ArchonShardColor {
+    Crimson,
+    Amber,
+    Azure,
+    Topaz,
+    Emerald,
+    Violet,
+}
+
+impl ArchonShardColor {
+    /// The name of the (non-tauforged) shard, e.g. `Crimson Archon Shard`.
+    pub fn shard_name(self) -> String {
+        format!("{self} Archon Shard")
+    }
+}
+
+/// The archons hunted in rotation, one per week.
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash, derive_more::Display)]
+pub enum Archon {
+    Amar,
+    Nira,
+    Boreal,
+}
+
+impl Archon {
+    /// Maps the worldstate boss key (e.g. `SORTIE_BOSS_AMAR`) to the archon.
+    pub fn from_boss_key(key: &str) -> Option<Self> {
+        match key {
+            "SORTIE_BOSS_AMAR" => Some(Archon::Amar),
+            "SORTIE_BOSS_NIRA" => Some(Archon::Nira),
+            "SORTIE_BOSS_BOREAL" => Some(Archon::Boreal),
+            _ => None,
+        }
+    }
+
+    pub fn shard_color(self) -> ArchonShardColor {
+        match self {
+            Archon::Amar => ArchonShardColor::Crimson,
+            Archon::Nira => ArchonShardColor::Amber,
+            Archon::Boreal => ArchonShardColor::Azure,
+        }
+    }
+
+    /// The archon hunted the week after this one. The rotation is Amar, Nira, Boreal.
+    pub fn next(self) -> Self {
+        match self {
+            Archon::Amar => Archon::Nira,
+            Archon::Nira => Archon::Boreal,
+            Archon::Boreal => Archon::Amar,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::target_types::worldstate_types::archon_hunt::{Archon, ArchonShardColor};
+
+    #[test]
+    fn test_rotation() {
+        assert_eq!(Archon::Amar.next(), Archon::Nira);
+        assert_eq!(Archon::Nira.next(), Archon::Boreal);
+        assert_eq!(Archon::Boreal.next(), Archon::Amar);
+
+        assert_eq!(
+            Archon::from_boss_key("SORTIE_BOSS_NIRA"),
+            Some(Archon::Nira)
+        );
+        assert_eq!(Archon::from_boss_key("SORTIE_BOSS_VOR"), None);
+
+        assert_eq!(Archon::Boreal.shard_color(), ArchonShardColor::Azure);
+        assert_eq!(
+            Archon::Nira.shard_color().shard_name(),
+            "Amber Archon Shard"
+        );
+    }
+}
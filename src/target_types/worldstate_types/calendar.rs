@@ -35,11 +35,22 @@ pub struct CalendarDay {
 pub enum CalendarEvent {
     Challenge(DisplayInfo),
 
-    Rewards([String; 2]),
+    Rewards([CalendarReward; 2]),
 
     Upgrades([DisplayInfo; 3]),
 }
 
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarReward {
+    pub name: String,
+
+    /// The unique name of the arcane, if the reward is one. Look it up with
+    /// [`Context::arcane`](crate::Context::arcane).
+    pub arcane: Option<String>,
+}
+
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Hash, Copy)]
 pub enum CalendarSeason {
@@ -11,8 +11,10 @@ pub struct Event {
 
     pub messages: Vec<EventMessage>,
 
+    /// The link the event points to. Site-relative paths are resolved to full URLs.
     pub prop: String,
 
+    /// Full URL of the event icon, if any.
     pub icon: Option<String>,
 
     pub priority: bool,
@@ -33,6 +35,37 @@ pub struct Event {
     pub event_end_date: Option<DateTime<Utc>>,
 }
 
+impl Event {
+    /// Returns the message for the given language, falling back to English (or the first message
+    /// if there is no English one either).
+    pub fn message_for(&self, language: Language) -> Option<&str> {
+        select_localized(&self.messages, language, |message| message.language)
+            .map(|message| message.message.as_str())
+    }
+
+    /// Returns the link for the given language, falling back to English (or the first link if
+    /// there is no English one either).
+    pub fn link_for(&self, language: Language) -> Option<&str> {
+        select_localized(&self.links, language, |link| link.language).map(|link| link.link.as_str())
+    }
+}
+
+fn select_localized<T>(
+    items: &[T],
+    language: Language,
+    language_of: impl Fn(&T) -> Language,
+) -> Option<&T> {
+    items
+        .iter()
+        .find(|item| language_of(item) == language)
+        .or_else(|| {
+            items
+                .iter()
+                .find(|item| language_of(item) == Language::English)
+        })
+        .or_else(|| items.first())
+}
+
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Hash)]
 #[serde(rename_all = "camelCase")]
@@ -50,3 +83,52 @@ pub struct EventMessage {
 
     pub message: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::target_types::{
+        language::Language,
+        worldstate_types::event::{Event, EventLink, EventMessage},
+    };
+
+    fn event() -> Event {
+        Event {
+            id: "id".to_owned(),
+            messages: vec![
+                EventMessage {
+                    language: Language::German,
+                    message: "Hallo".to_owned(),
+                },
+                EventMessage {
+                    language: Language::English,
+                    message: "Hello".to_owned(),
+                },
+            ],
+            prop: String::new(),
+            icon: None,
+            priority: false,
+            mobile_only: false,
+            community: None,
+            image_url: None,
+            date: None,
+            hide_end_date_modifier: None,
+            links: vec![EventLink {
+                language: Language::French,
+                link: "https://www.warframe.com/fr".to_owned(),
+            }],
+            event_end_date: None,
+        }
+    }
+
+    #[test]
+    fn test_message_for() {
+        let event = event();
+
+        assert_eq!(event.message_for(Language::German), Some("Hallo"));
+        assert_eq!(event.message_for(Language::Japanese), Some("Hello"));
+        assert_eq!(
+            event.link_for(Language::English),
+            Some("https://www.warframe.com/fr")
+        );
+    }
+}
@@ -1,10 +1,11 @@
 use chrono::{DateTime, Utc};
+use derive_more::Display;
 use serde::{Deserialize, Serialize};
 
-use crate::target_types::node::Node;
+use crate::target_types::{mission_type::MissionType, node::Node, relic::RelicReward};
 
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
-#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash, Display)]
 pub enum FissureTier {
     Lith,
     Meso,
@@ -14,6 +15,60 @@ pub enum FissureTier {
     Omnia,
 }
 
+impl FissureTier {
+    /// Whether relics of `relic_tier` can be opened in a fissure of this tier.
+    pub fn accepts(self, relic_tier: FissureTier) -> bool {
+        self == FissureTier::Omnia || self == relic_tier
+    }
+}
+
+/// How long a fissure mission takes, for ranking fissures.
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum FissureMissionKind {
+    /// Missions that end after a single objective, like Capture or Exterminate.
+    Fast,
+
+    /// Missions that go on for as long as the squad wants, like Survival or Defense.
+    Endless,
+
+    /// Everything else, like Mobile Defense or Hijack.
+    Other,
+}
+
+impl From<MissionType> for FissureMissionKind {
+    fn from(mission_type: MissionType) -> Self {
+        match mission_type {
+            MissionType::Capture
+            | MissionType::Exterminate
+            | MissionType::Rescue
+            | MissionType::Sabotage
+            | MissionType::Spy
+            | MissionType::Assassination
+            | MissionType::Rush => FissureMissionKind::Fast,
+
+            MissionType::Survival
+            | MissionType::Defense
+            | MissionType::Interception
+            | MissionType::Excavation
+            | MissionType::Disruption
+            | MissionType::Defection
+            | MissionType::InfestedSalvage
+            | MissionType::VoidFlood
+            | MissionType::VoidCascade
+            | MissionType::VoidArmaggedon
+            | MissionType::VoidArmageddon2
+            | MissionType::Alchemy
+            | MissionType::LegacyteHarvest
+            | MissionType::SanctuaryOnslaught
+            | MissionType::EliteSanctuaryOnslaught => FissureMissionKind::Endless,
+
+            _ => FissureMissionKind::Other,
+        }
+    }
+}
+
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Hash)]
 #[serde(rename_all = "camelCase")]
@@ -31,4 +86,29 @@ pub struct Fissure {
     pub tier: FissureTier,
 
     pub is_steel_path: bool,
+
+    /// Derived from the mission type of the node. `None` if the node is unknown.
+    pub mission_kind: Option<FissureMissionKind>,
+
+    /// Every relic that can be opened in this fissure, sorted by name. Unvaulted relics come
+    /// first.
+    #[serde(default)]
+    pub relics: Vec<FissureRelic>,
+}
+
+/// A relic that can be opened in a fissure.
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct FissureRelic {
+    /// The name without the refinement, e.g. `Lith A1`.
+    pub name: String,
+
+    pub tier: FissureTier,
+
+    pub rewards: Vec<RelicReward>,
+
+    /// Whether the relic no longer drops from missions or bounties. `None` if the drop tables
+    /// weren't loaded.
+    pub is_vaulted: Option<bool>,
 }
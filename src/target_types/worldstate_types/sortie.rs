@@ -2,7 +2,12 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    target_types::{mission_type::MissionType, node::Node},
+    target_types::{
+        faction::Faction,
+        mission_type::MissionType,
+        node::Node,
+        worldstate_types::archon_hunt::ArchonShardColor,
+    },
     wfcd_data::bounty_rewards::DropItem,
 };
 
@@ -20,7 +25,7 @@ pub struct Sortie {
 
     pub seed: u64,
 
-    pub boss: Option<String>,
+    pub boss: Option<SortieBoss>,
 
     pub reward_pool: Vec<DropItem>,
 
@@ -37,9 +42,31 @@ pub struct Sortie {
 pub struct SortieVariant {
     pub mission_type: MissionType,
 
-    pub modifier_type: String,
+    pub modifier: SortieModifier,
 
     pub node: Option<Node>,
 
     pub tileset: String,
 }
+
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct SortieModifier {
+    pub name: String,
+
+    pub description: Option<String>,
+}
+
+/// The boss of a sortie or an archon hunt.
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[derive(Debug, Deserialize, Serialize, PartialEq, Eq, Clone, Hash)]
+#[serde(rename_all = "camelCase")]
+pub struct SortieBoss {
+    pub name: String,
+
+    pub faction: Faction,
+
+    /// The color of the shard the boss drops, if it's an archon.
+    pub shard_color: Option<ArchonShardColor>,
+}
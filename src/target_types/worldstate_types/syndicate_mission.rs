@@ -1,4 +1,5 @@
 use chrono::{DateTime, Utc};
+use derive_more::Display;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
@@ -57,12 +58,43 @@ pub enum SyndicateMissionDetails {
     Empty,
 }
 
+/// The reward rotation of a bounty.
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash, Display)]
+pub enum BountyRotation {
+    A,
+    B,
+    C,
+}
+
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum BountyKind {
+    #[default]
+    Regular,
+
+    /// A Ghoul Purge bounty on the Plains of Eidolon.
+    Ghoul,
+
+    /// An Isolation Vault on the Cambion Drift.
+    IsolationVault,
+
+    /// A Narmer bounty on the Plains of Eidolon or the Orb Vallis.
+    Narmer,
+}
+
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SyndicateJob {
     pub job_type: Option<String>,
 
+    pub kind: BountyKind,
+
+    /// The rotation [`SyndicateJob::rewards`] are taken from, if the reward table is known.
+    pub rotation: Option<BountyRotation>,
+
     pub rewards: Vec<DropItem>,
 
     pub mastery_req: u64,
@@ -71,8 +103,14 @@ pub struct SyndicateJob {
 
     pub max_enemy_level: u64,
 
+    /// The standing awarded for each stage.
     pub xp_amounts: Vec<u64>,
 
+    pub stage_count: usize,
+
+    /// The standing awarded for completing every stage.
+    pub total_standing: u64,
+
     pub endless: bool,
 
     pub location_tag: Option<String>,
@@ -39,6 +39,19 @@ pub struct ArrivedVoidTrader {
     pub node: String,
 
     pub shop: Vec<ShopItem>,
+
+    /// The ducats needed to buy one of every item.
+    pub total_ducats: u64,
+
+    /// The credits needed to buy one of every item.
+    pub total_credits: u64,
+}
+
+impl ArrivedVoidTrader {
+    /// The items that can only be bought a limited number of times.
+    pub fn limited_items(&self) -> impl Iterator<Item = &ShopItem> {
+        self.shop.iter().filter(|item| item.limit.is_some())
+    }
 }
 
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
@@ -47,6 +60,11 @@ pub struct ArrivedVoidTrader {
 pub struct ShopItem {
     pub item_type: String,
 
+    /// The `/StoreItems` path of the item.
+    pub unique_name: String,
+
+    pub category: ShopItemCategory,
+
     pub prime_price: u64,
 
     pub regular_price: u64,
@@ -54,3 +72,23 @@ pub struct ShopItem {
     /// Limited per-user. Such as Baro's loot box
     pub limit: Option<u64>,
 }
+
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum ShopItemCategory {
+    Mod,
+    Arcane,
+    Weapon,
+    Warframe,
+    Companion,
+    Cosmetic,
+    Relic,
+
+    /// Relic packs and other booster packs, such as Baro's loot box.
+    RelicPack,
+
+    Resource,
+    Consumable,
+    Other,
+}
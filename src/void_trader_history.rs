@@ -0,0 +1,206 @@
+//! A persistent record of what the Void Trader sold and when.
+//!
+//! Feed every arrived trader into [`VoidTraderHistory::record`] and store the history between
+//! runs with [`VoidTraderHistory::write_to_file`], to answer questions like "when was this item
+//! last sold?".
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    io,
+    path::Path,
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::target_types::worldstate_types::void_trader::{ArrivedVoidTrader, ShopItemCategory};
+
+/// When and for how much an item was last sold.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoidTraderSighting {
+    pub name: String,
+
+    pub category: ShopItemCategory,
+
+    pub first_seen: DateTime<Utc>,
+
+    /// The arrival of the last visit the item was sold on.
+    pub last_seen: DateTime<Utc>,
+
+    /// On how many visits the item was sold.
+    pub visits: u32,
+
+    pub prime_price: u64,
+
+    pub regular_price: u64,
+
+    /// Arrivals of every recorded visit, so recording a visit again doesn't count it twice. The
+    /// trader's worldstate id stays the same across visits, so it can't tell them apart.
+    #[serde(default)]
+    visit_activations: BTreeSet<DateTime<Utc>>,
+}
+
+/// Every item the Void Trader was seen selling, keyed by unique name.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct VoidTraderHistory {
+    items: BTreeMap<String, VoidTraderSighting>,
+}
+
+impl VoidTraderHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the shop of a visit. Recording a visit again, even an older one, doesn't count it
+    /// twice. Recording the latest visit again only updates the prices.
+    pub fn record(&mut self, trader: &ArrivedVoidTrader) {
+        for item in &trader.shop {
+            let sighting = self
+                .items
+                .entry(item.unique_name.clone())
+                .or_insert_with(|| VoidTraderSighting {
+                    name: item.item_type.clone(),
+                    category: item.category,
+                    first_seen: trader.activation,
+                    last_seen: trader.activation,
+                    visits: 0,
+                    prime_price: item.prime_price,
+                    regular_price: item.regular_price,
+                    visit_activations: BTreeSet::new(),
+                });
+
+            if sighting.visit_activations.insert(trader.activation) {
+                sighting.visits += 1;
+            }
+
+            sighting.first_seen = sighting.first_seen.min(trader.activation);
+
+            // Older visits recorded late only count towards the visits
+            if trader.activation >= sighting.last_seen {
+                sighting.name.clone_from(&item.item_type);
+                sighting.category = item.category;
+                sighting.prime_price = item.prime_price;
+                sighting.regular_price = item.regular_price;
+                sighting.last_seen = trader.activation;
+            }
+        }
+    }
+
+    pub fn get(&self, unique_name: &str) -> Option<&VoidTraderSighting> {
+        self.items.get(unique_name)
+    }
+
+    /// Looks up an item by its display name, case insensitively.
+    pub fn find_by_name(&self, name: &str) -> Option<(&str, &VoidTraderSighting)> {
+        self.items
+            .iter()
+            .find(|(_, sighting)| sighting.name.eq_ignore_ascii_case(name))
+            .map(|(unique_name, sighting)| (unique_name.as_str(), sighting))
+    }
+
+    /// When the item was last sold.
+    pub fn last_seen(&self, unique_name: &str) -> Option<DateTime<Utc>> {
+        self.get(unique_name).map(|sighting| sighting.last_seen)
+    }
+
+    /// Every recorded item, keyed by unique name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &VoidTraderSighting)> {
+        self.items
+            .iter()
+            .map(|(unique_name, sighting)| (unique_name.as_str(), sighting))
+    }
+
+    /// Reads the history from `path`. An empty history if the file doesn't exist yet.
+    pub fn read_from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read(path) {
+            Ok(bytes) => serde_json::from_slice(&bytes).map_err(io::Error::other),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Atomically writes the history to `path`.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let tmp_path = path.with_added_extension("tmp");
+
+        fs::write(
+            &tmp_path,
+            serde_json::to_vec(self).map_err(io::Error::other)?,
+        )?;
+        fs::rename(&tmp_path, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+
+    use crate::{
+        target_types::worldstate_types::void_trader::{
+            ArrivedVoidTrader,
+            ShopItem,
+            ShopItemCategory,
+        },
+        void_trader_history::VoidTraderHistory,
+    };
+
+    /// Baro's worldstate id, the same on every visit
+    const ID: &str = "5d1e07a0a38e4a4fdd7cefca";
+
+    fn visit(activation: &str, prime_price: u64) -> ArrivedVoidTrader {
+        ArrivedVoidTrader {
+            id: ID.to_owned(),
+            activation: activation.parse::<DateTime<Utc>>().unwrap(),
+            expiry: activation.parse::<DateTime<Utc>>().unwrap(),
+            character: "Baro'Ki Teel".to_owned(),
+            node: "Larunda Relay".to_owned(),
+            shop: vec![ShopItem {
+                item_type: "Primed Flow".to_owned(),
+                unique_name:
+                    "/Lotus/StoreItems/Upgrades/Mods/Warframe/Expert/AvatarPowerMaxModExpert"
+                        .to_owned(),
+                category: ShopItemCategory::Mod,
+                prime_price,
+                regular_price: 200_000,
+                limit: None,
+            }],
+            total_ducats: prime_price,
+            total_credits: 200_000,
+        }
+    }
+
+    #[test]
+    fn test_record() {
+        let mut history = VoidTraderHistory::new();
+
+        history.record(&visit("2024-08-02T13:00:00Z", 300));
+        history.record(&visit("2025-10-10T13:00:00Z", 350));
+        history.record(&visit("2025-10-10T13:00:00Z", 350));
+        history.record(&visit("2023-01-06T13:00:00Z", 250));
+        // Replaying an older visit
+        history.record(&visit("2024-08-02T13:00:00Z", 300));
+
+        let path =
+            std::env::temp_dir().join(format!("void_trader_history_{}.json", std::process::id()));
+        history.write_to_file(&path).unwrap();
+
+        let history = VoidTraderHistory::read_from_file(&path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        let (unique_name, sighting) = history.find_by_name("primed flow").unwrap();
+
+        assert_eq!(sighting.visits, 3);
+        assert_eq!(sighting.prime_price, 350);
+        assert_eq!(
+            history.last_seen(unique_name),
+            "2025-10-10T13:00:00Z".parse().ok()
+        );
+        assert_eq!(
+            sighting.first_seen,
+            "2023-01-06T13:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+    }
+}
@@ -0,0 +1,195 @@
+//! WebAssembly bindings.
+//!
+//! Everything goes in and out as JSON strings. The [`Context`] is loaded from a snapshot written
+//! by [`ContextSnapshot::write_to_file`], as building it in the browser would need every source
+//! file.
+//!
+//! Run the tests with `wasm-pack test --node -- --no-default-features --features wasm`. The tests
+//! of the logic behind the bindings also run natively, with `cargo test --features wasm`.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    WorldState,
+    WorldstateError,
+    context_snapshot::ContextSnapshot,
+    core::Context,
+    cycles::{
+        cambion_drift::CambionDriftCycle,
+        cetus::CetusCycle,
+        duviri::DuviriCycle,
+        orb_vallis::OrbVallisCycle,
+    },
+};
+
+/// A loaded [`Context`], kept on the Rust side so it's only deserialized once.
+#[wasm_bindgen(js_name = Context)]
+pub struct WasmContext(Context);
+
+#[wasm_bindgen(js_class = Context)]
+impl WasmContext {
+    /// Loads the context from the bytes of a context snapshot.
+    #[wasm_bindgen(js_name = fromSnapshot)]
+    pub fn from_snapshot(bytes: &[u8]) -> Result<WasmContext, JsError> {
+        Ok(Self(ContextSnapshot::from_slice(bytes)?.into_context()))
+    }
+}
+
+/// Parses the raw worldstate and returns the parsed [`WorldState`] as JSON.
+#[wasm_bindgen(js_name = parseWorldState)]
+pub fn parse_world_state(worldstate: &str, ctx: &WasmContext) -> Result<String, JsError> {
+    Ok(parse_to_json(worldstate, &ctx.0)?)
+}
+
+/// The Cetus cycle at `timestamp` (milliseconds since the epoch, like `Date.now()`), as JSON.
+#[wasm_bindgen(js_name = cetusCycleAt)]
+pub fn cetus_cycle_at(timestamp: f64) -> Result<String, JsError> {
+    Ok(cycle_at(timestamp, CetusCycle::at)?)
+}
+
+/// The Cambion Drift cycle at `timestamp` (milliseconds since the epoch), as JSON.
+#[wasm_bindgen(js_name = cambionDriftCycleAt)]
+pub fn cambion_drift_cycle_at(timestamp: f64) -> Result<String, JsError> {
+    Ok(cycle_at(timestamp, CambionDriftCycle::at)?)
+}
+
+/// The Orb Vallis cycle at `timestamp` (milliseconds since the epoch), as JSON.
+#[wasm_bindgen(js_name = orbVallisCycleAt)]
+pub fn orb_vallis_cycle_at(timestamp: f64) -> Result<String, JsError> {
+    Ok(cycle_at(timestamp, OrbVallisCycle::at)?)
+}
+
+/// The Duviri cycle at `timestamp` (milliseconds since the epoch), as JSON.
+#[wasm_bindgen(js_name = duviriCycleAt)]
+pub fn duviri_cycle_at(timestamp: f64) -> Result<String, JsError> {
+    Ok(cycle_at(timestamp, DuviriCycle::at)?)
+}
+
+/// Errors of the bindings, before they are turned into a [`JsError`].
+///
+/// Creating a [`JsError`] needs a JS host, so the logic returns these instead and stays testable
+/// natively.
+#[derive(Debug, thiserror::Error)]
+enum BindingError {
+    #[error("Timestamp {0} is not a finite number")]
+    TimestampNotFinite(f64),
+
+    #[error("Timestamp {0} is out of range")]
+    TimestampOutOfRange(f64),
+
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    #[error(transparent)]
+    Worldstate(#[from] WorldstateError),
+}
+
+fn parse_to_json(worldstate: &str, ctx: &Context) -> Result<String, BindingError> {
+    to_json(&WorldState::parse(worldstate, ctx)?)
+}
+
+fn cycle_at<T: Serialize>(
+    timestamp: f64,
+    at: impl FnOnce(DateTime<Utc>) -> T,
+) -> Result<String, BindingError> {
+    to_json(&at(from_millis(timestamp)?))
+}
+
+/// `NaN` and the infinities are rejected, as the cast to `i64` would silently turn them into the
+/// epoch or a saturated date.
+fn from_millis(timestamp: f64) -> Result<DateTime<Utc>, BindingError> {
+    if !timestamp.is_finite() {
+        return Err(BindingError::TimestampNotFinite(timestamp));
+    }
+
+    DateTime::from_timestamp_millis(timestamp as i64)
+        .ok_or(BindingError::TimestampOutOfRange(timestamp))
+}
+
+fn to_json(value: &impl Serialize) -> Result<String, BindingError> {
+    Ok(serde_json::to_string(value)?)
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use crate::{
+        context_snapshot::ContextSnapshot,
+        context_sources::tests::fixture_sources,
+        wasm::{WasmContext, cetus_cycle_at, parse_world_state},
+    };
+
+    #[wasm_bindgen_test]
+    fn test_cetus_cycle_at() {
+        // 2026-02-04T16:09:00Z, the start of a known night
+        let cycle = cetus_cycle_at(1_770_221_340_000.0).unwrap();
+
+        assert!(cycle.contains(r#""state":"Night""#));
+        assert!(cycle.contains(r#""activation":"2026-02-04T16:09:00Z""#));
+        assert!(cetus_cycle_at(f64::NAN).is_err());
+    }
+
+    #[wasm_bindgen_test]
+    fn test_parse_invalid_world_state() {
+        let snapshot = ContextSnapshot::from_sources(&fixture_sources()).unwrap();
+
+        let mut bytes = Vec::new();
+        snapshot.to_writer(&mut bytes).unwrap();
+
+        let ctx = WasmContext::from_snapshot(&bytes).unwrap();
+
+        assert!(parse_world_state("{}", &ctx).is_err());
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod native_tests {
+    use crate::{
+        context_sources::tests::fixture_sources,
+        cycles::cetus::CetusCycle,
+        wasm::{BindingError, cycle_at, from_millis, parse_to_json},
+    };
+
+    #[test]
+    fn test_cycle_at() {
+        // 2026-02-04T16:09:00Z, the start of a known night
+        let cycle = cycle_at(1_770_221_340_000.0, CetusCycle::at).unwrap();
+
+        assert!(cycle.contains(r#""state":"Night""#));
+        assert!(cycle.contains(r#""activation":"2026-02-04T16:09:00Z""#));
+    }
+
+    #[test]
+    fn test_from_millis() {
+        assert_eq!(
+            from_millis(1_770_221_340_000.0).unwrap(),
+            "2026-02-04T16:09:00Z"
+                .parse::<chrono::DateTime<chrono::Utc>>()
+                .unwrap()
+        );
+        assert!(matches!(
+            from_millis(f64::MAX),
+            Err(BindingError::TimestampOutOfRange(_))
+        ));
+
+        for timestamp in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            assert!(matches!(
+                from_millis(timestamp),
+                Err(BindingError::TimestampNotFinite(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_parse_invalid_world_state() {
+        let ctx = fixture_sources().build().unwrap();
+
+        assert!(matches!(
+            parse_to_json("{}", &ctx),
+            Err(BindingError::Worldstate(_))
+        ));
+    }
+}
@@ -4,32 +4,32 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct BountyRewards {
-    #[serde(rename(deserialize = "cetusBountyRewards"))]
+    #[serde(rename(deserialize = "cetusBountyRewards"), alias = "cetus")]
     pub cetus: Vec<Bounty>,
 
-    #[serde(rename(deserialize = "solarisBountyRewards"))]
+    #[serde(rename(deserialize = "solarisBountyRewards"), alias = "solaris")]
     pub solaris: Vec<Bounty>,
 
-    #[serde(rename(deserialize = "deimosRewards"))]
+    #[serde(rename(deserialize = "deimosRewards"), alias = "deimos")]
     pub deimos: Vec<Bounty>,
 
-    #[serde(rename(deserialize = "zarimanRewards"))]
+    #[serde(rename(deserialize = "zarimanRewards"), alias = "zariman")]
     pub zariman: Vec<Bounty>,
 
-    #[serde(rename(deserialize = "entratiLabRewards"))]
+    #[serde(rename(deserialize = "entratiLabRewards"), alias = "cavia")]
     pub cavia: Vec<Bounty>,
 
-    #[serde(rename(deserialize = "hexRewards"))]
+    #[serde(rename(deserialize = "hexRewards"), alias = "hex")]
     pub hex: Vec<Bounty>,
 
-    #[serde(rename(deserialize = "sortieRewards"))]
+    #[serde(rename(deserialize = "sortieRewards"), alias = "sortie_rewards")]
     pub sortie_rewards: Vec<DropItem>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Bounty {
-    #[serde(rename(deserialize = "_id"), skip_serializing)]
+    #[serde(rename(deserialize = "_id"), skip_serializing, default)]
     pub id: String,
 
     pub bounty_level: String,
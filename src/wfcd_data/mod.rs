@@ -1,8 +1,11 @@
 pub mod bounty_rewards;
 pub mod language_item;
+pub mod relic_drops;
 pub mod sortie_data;
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
 
 use crate::wfcd_data::{
     bounty_rewards::{BountyRewards, DropItem},
@@ -10,7 +13,7 @@ use crate::wfcd_data::{
     sortie_data::SortieData,
 };
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WorldstateData {
     pub language_items: LanguageItemMap,
     pub sortie_data: SortieData,
@@ -18,4 +21,9 @@ pub struct WorldstateData {
     pub archon_hunt_rewards: Vec<DropItem>,
     pub hubs: HashMap<String, String>,
     pub archon_shards_store_item: HashMap<String, String>,
+
+    /// Relics that drop from missions or bounties, like `Lith A1`. `None` if the drop tables
+    /// didn't contain the mission rewards.
+    #[serde(default)]
+    pub unvaulted_relics: Option<BTreeSet<String>>,
 }
@@ -0,0 +1,127 @@
+use std::collections::{BTreeSet, HashMap};
+
+use serde::Deserialize;
+
+use crate::wfcd_data::bounty_rewards::{BountyRewards, DropItem};
+
+/// The drop tables (`data.json` of the drops).
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Drops {
+    #[serde(flatten)]
+    pub rewards: BountyRewards,
+
+    /// Keyed by planet, then by node. `None` if the drop tables don't contain them.
+    #[serde(default)]
+    pub mission_rewards: Option<HashMap<String, HashMap<String, MissionNodeRewards>>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MissionNodeRewards {
+    pub rewards: MissionRewardTable,
+}
+
+/// The rewards of a node, either per rotation or for the whole mission.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum MissionRewardTable {
+    Rotations(HashMap<String, Vec<DropItem>>),
+    Single(Vec<DropItem>),
+}
+
+impl MissionRewardTable {
+    fn items(&self) -> Box<dyn Iterator<Item = &DropItem> + '_> {
+        match self {
+            MissionRewardTable::Rotations(rotations) => Box::new(rotations.values().flatten()),
+            MissionRewardTable::Single(items) => Box::new(items.iter()),
+        }
+    }
+}
+
+impl Drops {
+    /// Collects the relics that currently drop from missions or bounties (e.g. `Lith A1`).
+    ///
+    /// Returns `None` if the drop tables don't contain the mission rewards.
+    pub fn unvaulted_relics(&self) -> Option<BTreeSet<String>> {
+        let mission_rewards = self.mission_rewards.as_ref()?;
+
+        let rewards = &self.rewards;
+        let bounty_items = [
+            &rewards.cetus,
+            &rewards.solaris,
+            &rewards.deimos,
+            &rewards.zariman,
+            &rewards.cavia,
+            &rewards.hex,
+        ]
+        .into_iter()
+        .flatten()
+        .flat_map(|bounty| bounty.rewards.values().flatten());
+
+        let mission_items = mission_rewards
+            .values()
+            .flat_map(HashMap::values)
+            .flat_map(|node| node.rewards.items());
+
+        Some(
+            bounty_items
+                .chain(mission_items)
+                .filter_map(|item| item.item_name.strip_suffix(" Relic"))
+                .map(str::to_owned)
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::wfcd_data::relic_drops::Drops;
+
+    #[test]
+    fn test_unvaulted_relics() {
+        let drops: Drops = serde_json::from_str(
+            r#"{
+                "missionRewards": {
+                    "Mercury": {
+                        "Apollodorus": {
+                            "rewards": { "A": [{ "itemName": "Lith A1 Relic", "rarity": "Common", "chance": 20 }] }
+                        },
+                        "Caloris": {
+                            "rewards": [{ "itemName": "Meso D4 Relic", "rarity": "Common", "chance": 20 }]
+                        }
+                    }
+                },
+                "cetusBountyRewards": [{
+                    "bountyLevel": "Level 5 - 15 Cetus Bounty",
+                    "rewards": { "A": [{ "itemName": "Neo B2 Relic", "rarity": "Common", "chance": 20 }] }
+                }],
+                "solarisBountyRewards": [],
+                "deimosRewards": [],
+                "zarimanRewards": [],
+                "entratiLabRewards": [],
+                "hexRewards": [{
+                    "bountyLevel": "Level 65 - 70 Hex Bounty",
+                    "rewards": { "C": [{ "itemName": "Axi C3 Relic", "rarity": "Rare", "chance": 10 }] }
+                }],
+                "sortieRewards": []
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            drops
+                .unvaulted_relics()
+                .unwrap()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            ["Axi C3", "Lith A1", "Meso D4", "Neo B2"]
+        );
+
+        let drops = Drops {
+            mission_rewards: None,
+            ..drops
+        };
+
+        assert_eq!(drops.unvaulted_relics(), None);
+    }
+}
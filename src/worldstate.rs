@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    BlockingContextProvider,
     ContextProvider,
-    core::{ContextRef, Resolve},
+    core::{Context, ContextRef, Resolve},
     cycles::{
         cambion_drift::CambionDriftCycle,
         cetus::CetusCycle,
@@ -95,8 +96,8 @@ pub(crate) struct WorldStateUnmapped {
 
 impl WorldStateUnmapped {
     pub fn map(self, ctx: ContextRef<'_>) -> WorldState {
-        let events = self.events.resolve(());
-        let fissures = self.fissures.resolve(ctx);
+        let events = self.events.resolve(ctx.endpoints);
+        let fissures = self.fissures.resolve((ctx, &ctx.relics()));
         let alerts = self.alerts.resolve(ctx);
         let sorties = self.sorties.resolve(ctx);
         let goals = self.goals.resolve(ctx);
@@ -199,6 +200,35 @@ pub struct WorldState {
 }
 
 impl WorldState {
+    /// Parses the raw worldstate with an already loaded [`Context`].
+    ///
+    /// The mapping is pure CPU work, so this doesn't need an async runtime.
+    pub fn parse(s: &str, ctx: &Context) -> Result<WorldState, WorldstateError> {
+        let ws_unmapped = serde_json::from_str::<WorldStateUnmapped>(s)?;
+
+        Ok(ws_unmapped.map(ctx.as_ref()))
+    }
+
+    /// Parses the raw worldstate, loading the [`Context`] from a [`BlockingContextProvider`].
+    ///
+    /// The context is only loaded once the worldstate deserialized successfully.
+    pub fn from_str_blocking<C>(s: &str, provider: C) -> Result<WorldState, WorldstateError>
+    where
+        C: BlockingContextProvider,
+        C::Err: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        let ws_unmapped = serde_json::from_str::<WorldStateUnmapped>(s)?;
+
+        let ctx = provider
+            .get_ctx_blocking()
+            .map_err(|err| WorldstateError::Provider(err.into()))?;
+
+        Ok(ws_unmapped.map(ctx.as_ref()))
+    }
+
+    /// Parses the raw worldstate, loading the [`Context`] from a [`ContextProvider`].
+    ///
+    /// The context is only loaded once the worldstate deserialized successfully.
     pub async fn from_str<C>(s: &str, provider: C) -> Result<WorldState, WorldstateError>
     where
         C: ContextProvider,
@@ -214,3 +244,81 @@ impl WorldState {
         Ok(ws_unmapped.map(ctx.as_ref()))
     }
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::{
+        context_sources::{InMemoryContextProvider, tests::fixture_sources},
+        worldstate::{WorldState, WorldstateError},
+    };
+
+    /// A raw worldstate with only the fields that can't be empty filled in.
+    pub(crate) const RAW: &str = r#"{
+        "Events": [],
+        "ActiveMissions": [],
+        "Alerts": [],
+        "Sorties": [],
+        "Goals": [],
+        "LiteSorties": [],
+        "SyndicateMissions": [],
+        "FlashSales": [],
+        "Invasions": [],
+        "VoidTraders": [],
+        "PrimeVaultTraders": [],
+        "VoidStorms": [],
+        "DailyDeals": [],
+        "EndlessXpChoices": [
+            { "Category": "EXC_HARD", "Choices": ["Rhino"] },
+            { "Category": "EXC_NORMAL", "Choices": ["Excalibur", "Mag"] }
+        ],
+        "SeasonInfo": {
+            "Activation": { "$date": { "$numberLong": "1700000000000" } },
+            "Expiry": { "$date": { "$numberLong": "1800000000000" } },
+            "AffiliationTag": "RadioLegionIntermission13Syndicate",
+            "Season": 13,
+            "Phase": 0,
+            "Params": "",
+            "ActiveChallenges": []
+        },
+        "KnownCalendarSeasons": [],
+        "Conquests": []
+    }"#;
+
+    #[test]
+    fn test_parse() {
+        let ctx = fixture_sources().build().unwrap();
+        let worldstate = WorldState::parse(RAW, &ctx).unwrap();
+
+        assert_eq!(worldstate.circuit.normal_choices, ["Excalibur", "Mag"]);
+        assert_eq!(worldstate.circuit.steel_path_choices, ["Rhino"]);
+        assert_eq!(worldstate.nightwave.season, 13);
+        assert!(worldstate.void_trader.is_none());
+
+        assert!(matches!(
+            WorldState::parse("{}", &ctx),
+            Err(WorldstateError::WorldstateDeserialization(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_str_blocking() {
+        let worldstate =
+            WorldState::from_str_blocking(RAW, InMemoryContextProvider(fixture_sources())).unwrap();
+
+        assert_eq!(worldstate.circuit.normal_choices, ["Excalibur", "Mag"]);
+
+        let mut sources = fixture_sources();
+        sources.worldstate_data.sol_nodes = b"not json".to_vec();
+
+        assert!(matches!(
+            WorldState::from_str_blocking(RAW, InMemoryContextProvider(sources.clone())),
+            Err(WorldstateError::Provider(_))
+        ));
+
+        // Malformed input fails before the context is loaded
+        assert!(matches!(
+            WorldState::from_str_blocking("{}", InMemoryContextProvider(sources)),
+            Err(WorldstateError::WorldstateDeserialization(_))
+        ));
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
         resolve_with,
         sol_node::SolNode,
     },
-    target_types::worldstate_types::archon_hunt::{ArchonHunt, ArchonHuntMission},
+    target_types::worldstate_types::archon_hunt::{Archon, ArchonHunt, ArchonHuntMission},
     worldstate_model::{Id, WorldstateMissionType, deserialize_mongo_date},
 };
 
@@ -39,13 +39,18 @@ impl Resolve<ContextRef<'_>> for ArchonHuntUnmapped {
     type Output = ArchonHunt;
 
     fn resolve(self, ctx: ContextRef<'_>) -> Self::Output {
+        let archon = Archon::from_boss_key(&self.boss.0);
+
         ArchonHunt {
             id: self.id.oid,
             activation: self.activation,
             expiry: self.expiry,
             reward_pool: ctx.worldstate_data.archon_hunt_rewards.clone(),
             seed: self.seed,
-            boss: self.boss.resolve(ctx).map(|boss| &boss.name).cloned(),
+            boss: self.boss.resolve(ctx),
+            archon,
+            guaranteed_reward: archon.map(|archon| archon.shard_color().shard_name()),
+            next_archon: archon.map(Archon::next),
             missions: self.missions.resolve(ctx),
         }
     }
@@ -69,3 +74,78 @@ impl Resolve<ContextRef<'_>> for MissionUnmapped {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        context_sources::tests::fixture_sources,
+        core::{Context, Resolve},
+        target_types::{
+            faction::Faction,
+            worldstate_types::archon_hunt::{Archon, ArchonShardColor},
+        },
+        worldstate_model::archon_hunt::ArchonHuntUnmapped,
+    };
+
+    fn context() -> Context {
+        let mut sources = fixture_sources();
+        sources.worldstate_data.sortie_data = br#"{
+            "modifierTypes": {},
+            "modifierDescriptions": {},
+            "bosses": {
+                "SORTIE_BOSS_AMAR": { "name": "Archon Amar", "faction": "Narmer" },
+                "SORTIE_BOSS_VOR": { "name": "Captain Vor", "faction": "Grineer" }
+            },
+            "modifiers": []
+        }"#
+        .to_vec();
+
+        sources.build().unwrap()
+    }
+
+    fn archon_hunt(boss: &str) -> ArchonHuntUnmapped {
+        let date = r#"{ "$date": { "$numberLong": "1614776842000" } }"#;
+
+        serde_json::from_str(&format!(
+            r#"{{
+                "_id": {{ "$oid": "1" }},
+                "Activation": {date},
+                "Expiry": {date},
+                "Reward": "/Lotus/Types/Game/MissionDecks/ArchonSortieRewards",
+                "Seed": 1,
+                "Boss": "{boss}",
+                "Missions": []
+            }}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_archon() {
+        let ctx = context();
+
+        let archon_hunt = archon_hunt("SORTIE_BOSS_AMAR").resolve(ctx.as_ref());
+        let boss = archon_hunt.boss.unwrap();
+
+        assert_eq!(archon_hunt.archon, Some(Archon::Amar));
+        assert_eq!(
+            archon_hunt.guaranteed_reward.as_deref(),
+            Some("Crimson Archon Shard")
+        );
+        assert_eq!(archon_hunt.next_archon, Some(Archon::Nira));
+        assert_eq!(boss.name, "Archon Amar");
+        assert_eq!(boss.faction, Faction::Narmer);
+        assert_eq!(boss.shard_color, Some(ArchonShardColor::Crimson));
+    }
+
+    #[test]
+    fn test_unknown_boss() {
+        let ctx = context();
+
+        let archon_hunt = archon_hunt("SORTIE_BOSS_VOR").resolve(ctx.as_ref());
+
+        assert_eq!(archon_hunt.archon, None);
+        assert_eq!(archon_hunt.guaranteed_reward, None);
+        assert_eq!(archon_hunt.next_archon, None);
+    }
+}
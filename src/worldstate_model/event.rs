@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     core::Resolve,
+    endpoints::Endpoints,
     target_types::worldstate_types::event::{Event, EventLink, EventMessage},
     worldstate_model::{Id, WorldstateLanguage, deserialize_mongo_date_opt},
 };
@@ -41,15 +42,15 @@ pub struct EventUnmapped {
     event_end_date: Option<DateTime<Utc>>,
 }
 
-impl Resolve<()> for EventUnmapped {
+impl Resolve<&Endpoints> for EventUnmapped {
     type Output = Event;
 
-    fn resolve(self, _: ()) -> Self::Output {
+    fn resolve(self, endpoints: &Endpoints) -> Self::Output {
         Event {
             id: self.id.oid,
             messages: self.messages.resolve(()),
-            prop: self.prop,
-            icon: self.icon,
+            prop: endpoints.website_url(self.prop),
+            icon: self.icon.map(|icon| endpoints.content_url(icon)),
             priority: self.priority,
             mobile_only: self.mobile_only,
             community: self.community,
@@ -99,3 +100,72 @@ impl Resolve<()> for EventMessageUnmapped {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{core::Resolve, endpoints::Endpoints, worldstate_model::event::EventUnmapped};
+
+    fn event(prop: &str, icon: &str) -> EventUnmapped {
+        serde_json::from_str(&format!(
+            r#"{{
+                "_id": {{ "$oid": "1" }},
+                "Messages": [],
+                "Prop": "{prop}",
+                "Icon": "{icon}",
+                "Priority": false,
+                "MobileOnly": false
+            }}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_resolve_url() {
+        let endpoints = Endpoints::default();
+
+        let relative = event(
+            "/news/some-article",
+            "/Lotus/Interface/Icons/DiscordIconNoBacker.png",
+        )
+        .resolve(&endpoints);
+
+        assert_eq!(relative.prop, "https://www.warframe.com/news/some-article");
+        assert_eq!(
+            relative.icon.as_deref(),
+            Some(
+                "https://content.warframe.com/PublicExport/Lotus/Interface/Icons/DiscordIconNoBacker.png"
+            )
+        );
+
+        let absolute = event(
+            "https://forums.warframe.com/topic/1",
+            "https://example.com/icon.png",
+        )
+        .resolve(&endpoints);
+
+        assert_eq!(absolute.prop, "https://forums.warframe.com/topic/1");
+        assert_eq!(
+            absolute.icon.as_deref(),
+            Some("https://example.com/icon.png")
+        );
+
+        assert_eq!(event("", "").resolve(&endpoints).prop, "");
+    }
+
+    #[test]
+    fn test_resolve_url_with_endpoints() {
+        let endpoints = Endpoints {
+            content: "http://localhost/content/".to_owned(),
+            website: "http://localhost/site".to_owned(),
+            ..Default::default()
+        };
+
+        let event = event("/news/some-article", "/Lotus/Icon.png").resolve(&endpoints);
+
+        assert_eq!(event.prop, "http://localhost/site/news/some-article");
+        assert_eq!(
+            event.icon.as_deref(),
+            Some("http://localhost/content/Lotus/Icon.png")
+        );
+    }
+}
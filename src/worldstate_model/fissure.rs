@@ -3,7 +3,8 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     core::{ContextRef, Resolve, sol_node::SolNode},
-    target_types::worldstate_types::fissure::{Fissure, FissureTier},
+    relic_catalogue::RelicCatalogue,
+    target_types::worldstate_types::fissure::{Fissure, FissureMissionKind, FissureTier},
     worldstate_model::{Id, deserialize_mongo_date},
 };
 
@@ -60,25 +61,36 @@ pub(crate) struct FissureUnmapped {
     pub hard: bool,
 }
 
-impl Resolve<ContextRef<'_>> for FissureUnmapped {
+impl Resolve<(ContextRef<'_>, &RelicCatalogue<'_>)> for FissureUnmapped {
     type Output = Fissure;
 
-    fn resolve(self, ctx: ContextRef) -> Self::Output {
+    fn resolve(self, (ctx, relics): (ContextRef<'_>, &RelicCatalogue<'_>)) -> Self::Output {
+        let node = self.node.resolve(ctx).cloned();
+        let tier = self.modifier.resolve(());
+
         Fissure {
             id: self.id.oid,
-            node: self.node.resolve(ctx).cloned(),
+            mission_kind: node
+                .as_ref()
+                .map(|node| FissureMissionKind::from(node.mission_type)),
+            node,
             seed: self.seed,
             activation: self.activation,
             expiry: self.expiry,
-            tier: self.modifier.resolve(()),
+            tier,
             is_steel_path: self.hard,
+            relics: relics.fissure_relics(tier),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::worldstate_model::fissure::{FissureUnmapped, TierUnmapped};
+    use crate::{
+        context_sources::tests::fixture_sources,
+        core::Resolve,
+        worldstate_model::fissure::{FissureUnmapped, TierUnmapped},
+    };
 
     #[test]
     fn test_deserialization() {
@@ -111,4 +123,37 @@ mod tests {
         assert_eq!(fissure.id.oid, "603f8a0a1a8c1234567890ab");
         assert_eq!(fissure.modifier, TierUnmapped::Omnia)
     }
+
+    #[test]
+    fn test_resolve_relics() {
+        let mut sources = fixture_sources();
+        sources.exports.relic_arcane = br#"{ "ExportRelicArcane": [{
+            "uniqueName": "/Lotus/Types/Game/Projections/T1VoidProjectionA1Bronze",
+            "name": "Lith A1 Intact",
+            "codexSecret": false,
+            "description": "A relic",
+            "relicRewards": []
+        }] }"#
+            .to_vec();
+
+        let ctx = sources.build().unwrap();
+
+        let fissure: FissureUnmapped = serde_json::from_str(
+            r#"{
+                "_id": { "$oid": "1" },
+                "Seed": 1,
+                "Activation": { "$date": { "$numberLong": "1614776842000" } },
+                "Expiry": { "$date": { "$numberLong": "1614780442000" } },
+                "Node": "SolNode1",
+                "Modifier": "VoidT1"
+            }"#,
+        )
+        .unwrap();
+
+        let fissure = fissure.resolve((ctx.as_ref(), &ctx.relics()));
+        let json = serde_json::to_value(&fissure).unwrap();
+
+        assert_eq!(fissure.relics.len(), 1);
+        assert_eq!(json["relics"][0]["name"], "Lith A1");
+    }
 }
@@ -15,9 +15,12 @@ use crate::{
         faction::Faction,
         language::Language,
         mission_type::MissionType,
-        worldstate_types::syndicate::SyndicateType,
+        worldstate_types::{
+            syndicate::SyndicateType,
+            syndicate_mission::{BountyKind, BountyRotation},
+        },
     },
-    wfcd_data::bounty_rewards::{Bounty, DropItem},
+    wfcd_data::bounty_rewards::{Bounty, BountyRewards, DropItem},
 };
 
 pub mod alert;
@@ -421,11 +424,56 @@ impl<'de> Deserialize<'de> for WorldstateSyndicateType {
     }
 }
 
-static BOUNTY_REWARD_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new("Tier(?:(?:[ABCDE])|Narmer)Table([ABC])Rewards").unwrap());
+static BOUNTY_REWARD_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new("Tier(?:[ABCDE]|(?<narmer>Narmer))Table(?<rotation>[ABC])Rewards").unwrap()
+});
 
 static GHOUL_REWARD_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new("GhoulBountyTable([AB])Rewards").unwrap());
+    LazyLock::new(|| Regex::new("GhoulBountyTable(?<rotation>[AB])Rewards").unwrap());
+
+/// What the reward table of a bounty tells about it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BountyTable {
+    pub kind: BountyKind,
+    pub rotation: Option<BountyRotation>,
+}
+
+impl BountyTable {
+    /// Parses a reward table like `/Lotus/Types/Game/MissionDecks/.../TierATableBRewards`.
+    pub fn parse(resource: &str, is_vault: bool) -> Self {
+        let table = resource.rsplit('/').next().unwrap_or_default();
+
+        let (kind, captures) = if let Some(captures) = GHOUL_REWARD_REGEX.captures(table) {
+            (BountyKind::Ghoul, Some(captures))
+        } else {
+            let captures = BOUNTY_REWARD_REGEX.captures(table);
+
+            let kind = if is_vault {
+                BountyKind::IsolationVault
+            } else if captures
+                .as_ref()
+                .is_some_and(|captures| captures.name("narmer").is_some())
+            {
+                BountyKind::Narmer
+            } else {
+                BountyKind::Regular
+            };
+
+            (kind, captures)
+        };
+
+        let rotation = captures
+            .and_then(|captures| captures.name("rotation"))
+            .and_then(|rotation| match rotation.as_str() {
+                "A" => Some(BountyRotation::A),
+                "B" => Some(BountyRotation::B),
+                "C" => Some(BountyRotation::C),
+                _ => None,
+            });
+
+        Self { kind, rotation }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct RotationalRewardContext<'a> {
@@ -449,6 +497,55 @@ fn lookup_bounty(
         .cloned()
 }
 
+/// The drop table of a bounty and the variant its entries are labelled with.
+///
+/// The drop tables label every bounty as `Level <min> - <max> <variant>`, where the variant is
+/// named after the place the bounty is taken in rather than the syndicate offering it. The Entrati
+/// Lab syndicate (the Cavia) hands out its bounties in the Sanctum Anatomica, so they are labelled
+/// `Sanctum Bounty`, not `Cavia Bounty`.
+fn bounty_table(
+    syndicate_type: WorldstateSyndicateType,
+    kind: BountyKind,
+    rewards: &BountyRewards,
+) -> Option<(&'static str, &[Bounty])> {
+    let table = match syndicate_type {
+        WorldstateSyndicateType::CetusSyndicate => (
+            match kind {
+                BountyKind::Ghoul => "Ghoul Bounty",
+                BountyKind::Narmer => "Narmer Bounty",
+                _ => "Cetus Bounty",
+            },
+            &rewards.cetus,
+        ),
+
+        WorldstateSyndicateType::EntratiSyndicate => (
+            match kind {
+                BountyKind::IsolationVault => "Isolation Vault",
+                _ => "Cambion Drift Bounty",
+            },
+            &rewards.deimos,
+        ),
+
+        WorldstateSyndicateType::SolarisSyndicate => (
+            match kind {
+                BountyKind::Narmer => "Narmer Bounty",
+                _ => "Orb Vallis Bounty",
+            },
+            &rewards.solaris,
+        ),
+
+        WorldstateSyndicateType::ZarimanSyndicate => ("Zariman Bounty", &rewards.zariman),
+
+        WorldstateSyndicateType::EntratiLabSyndicate => ("Sanctum Bounty", &rewards.cavia),
+
+        WorldstateSyndicateType::HexSyndicate => ("Hex Bounty", &rewards.hex),
+
+        _ => return None,
+    };
+
+    Some((table.0, table.1.as_slice()))
+}
+
 impl Resolve<RotationalRewardContext<'_>> for InternalPath<resolve_with::RotationalReward> {
     type Output = Option<Vec<DropItem>>;
 
@@ -463,61 +560,303 @@ impl Resolve<RotationalRewardContext<'_>> for InternalPath<resolve_with::Rotatio
             is_vault,
         }: RotationalRewardContext<'_>,
     ) -> Self::Output {
-        let table = resource.split('/').next_back()?;
-
-        let level_range_string = format!("{} - {}", min_level, max_level);
-
-        let bounty_tier = BOUNTY_REWARD_REGEX
-            .captures(table)
-            .and_then(|cap| cap.get(1))
-            .map(|group| group.as_str())?;
-
-        match syndicate_type {
-            WorldstateSyndicateType::CetusSyndicate => {
-                let level_string;
-                let tier;
-
-                if let Some(ghoul_tier) = GHOUL_REWARD_REGEX
-                    .captures(table)
-                    .and_then(|cap| cap.get(1))
-                    .map(|group| group.as_str())
-                {
-                    level_string = format!("Level {level_range_string} Ghoul Bounty");
-                    tier = ghoul_tier;
-                } else {
-                    level_string = format!("Level {level_range_string} Cetus Bounty");
-                    tier = bounty_tier;
-                }
+        let BountyTable { kind, rotation } = BountyTable::parse(&resource, is_vault);
+        let bounty_tier = rotation?.to_string();
 
-                lookup_bounty(&level_string, tier, &ctx.worldstate_data.rewards.cetus)
-            },
+        let (variant, bounties) = bounty_table(syndicate_type, kind, &ctx.worldstate_data.rewards)?;
 
-            WorldstateSyndicateType::EntratiSyndicate => {
-                let variant = match is_vault {
-                    true => "Isolation Vault",
-                    false => "Cambion Drift Bounty",
-                };
+        let level_string = format!("Level {min_level} - {max_level} {variant}");
 
-                let level_string = format!("Level {level_range_string} {variant}");
+        lookup_bounty(&level_string, &bounty_tier, bounties)
+    }
+}
 
-                lookup_bounty(
-                    &level_string,
-                    bounty_tier,
-                    &ctx.worldstate_data.rewards.deimos,
-                )
-            },
+#[cfg(test)]
+mod tests {
+    use crate::{
+        context_sources::tests::fixture_sources,
+        core::{Context, InternalPath, Resolve, resolve_with},
+        target_types::worldstate_types::syndicate_mission::{BountyKind, BountyRotation},
+        worldstate_model::{BountyTable, RotationalRewardContext, WorldstateSyndicateType},
+    };
+
+    #[test]
+    fn test_bounty_table() {
+        let parse = |table: &str, is_vault| {
+            BountyTable::parse(
+                &format!("/Lotus/Types/Game/MissionDecks/EidolonJobMissionRewards/{table}"),
+                is_vault,
+            )
+        };
+
+        assert_eq!(
+            parse("TierBTableCRewards", false),
+            BountyTable {
+                kind: BountyKind::Regular,
+                rotation: Some(BountyRotation::C)
+            }
+        );
+        assert_eq!(
+            parse("GhoulBountyTableBRewards", false),
+            BountyTable {
+                kind: BountyKind::Ghoul,
+                rotation: Some(BountyRotation::B)
+            }
+        );
+        assert_eq!(
+            parse("TierNarmerTableARewards", false),
+            BountyTable {
+                kind: BountyKind::Narmer,
+                rotation: Some(BountyRotation::A)
+            }
+        );
+        assert_eq!(
+            parse("VaultBountyRewards", true),
+            BountyTable {
+                kind: BountyKind::IsolationVault,
+                rotation: None
+            }
+        );
+    }
 
-            WorldstateSyndicateType::SolarisSyndicate => {
-                let level_string = format!("Level {level_range_string} Orb Vallis Bounty");
+    /// Drop tables with one bounty of every variant, labelled like in the real drop tables.
+    const DROPS: &[u8] = br#"{
+        "cetusBountyRewards": [{
+            "bountyLevel": "Level 40 - 60 Cetus Bounty",
+            "rewards": { "A": [{ "itemName": "Cetus Wisp", "rarity": "Common", "chance": 50 }] }
+        }, {
+            "bountyLevel": "Level 40 - 60 Ghoul Bounty",
+            "rewards": { "A": [{ "itemName": "Gara Neuroptics", "rarity": "Rare", "chance": 10 }] }
+        }, {
+            "bountyLevel": "Level 50 - 70 Narmer Bounty",
+            "rewards": { "B": [{ "itemName": "Eternal War", "rarity": "Rare", "chance": 10 }] }
+        }],
+        "solarisBountyRewards": [{
+            "bountyLevel": "Level 50 - 70 Orb Vallis Bounty",
+            "rewards": { "B": [{ "itemName": "Toroid", "rarity": "Common", "chance": 50 }] }
+        }, {
+            "bountyLevel": "Level 50 - 70 Narmer Bounty",
+            "rewards": { "B": [{ "itemName": "Father", "rarity": "Rare", "chance": 10 }] }
+        }],
+        "deimosRewards": [{
+            "bountyLevel": "Level 30 - 40 Cambion Drift Bounty",
+            "rewards": { "C": [{ "itemName": "Scintillant", "rarity": "Rare", "chance": 10 }] }
+        }, {
+            "bountyLevel": "Level 30 - 40 Isolation Vault",
+            "rewards": { "C": [{ "itemName": "Theorem Contagion", "rarity": "Rare", "chance": 10 }] }
+        }],
+        "zarimanRewards": [{
+            "bountyLevel": "Level 50 - 55 Zariman Bounty",
+            "rewards": { "B": [{ "itemName": "Voidplume Down", "rarity": "Common", "chance": 50 }] }
+        }],
+        "entratiLabRewards": [{
+            "bountyLevel": "Level 55 - 60 Sanctum Bounty",
+            "rewards": { "A": [{ "itemName": "Vessel Capillaries", "rarity": "Common", "chance": 25 }] }
+        }],
+        "hexRewards": [{
+            "bountyLevel": "Level 65 - 70 Hex Bounty",
+            "rewards": { "C": [{ "itemName": "Efervon Sample", "rarity": "Rare", "chance": 10 }] }
+        }],
+        "sortieRewards": []
+    }"#;
+
+    /// Resolves the rewards of a bounty and returns the name of the first one.
+    fn resolve(
+        ctx: &Context,
+        syndicate_type: WorldstateSyndicateType,
+        table: &str,
+        (min_level, max_level): (u64, u64),
+        is_vault: bool,
+    ) -> Option<String> {
+        let resource = format!("/Lotus/Types/Game/MissionDecks/JobMissionRewards/{table}");
+
+        InternalPath::<resolve_with::RotationalReward>::from(resource.clone())
+            .resolve(RotationalRewardContext {
+                inner_ctx: ctx.as_ref(),
+                syndicate_type,
+                resource,
+                min_level,
+                max_level,
+                is_vault,
+            })
+            .map(|rewards| rewards[0].item_name.clone())
+    }
 
-                lookup_bounty(
-                    &level_string,
-                    bounty_tier,
-                    &ctx.worldstate_data.rewards.solaris,
-                )
-            },
+    fn context() -> Context {
+        let mut sources = fixture_sources();
+        sources.worldstate_data.drops = DROPS.to_vec();
 
-            _ => None,
+        sources.build().unwrap()
+    }
+
+    #[test]
+    fn test_new_syndicate_rewards() {
+        let ctx = context();
+
+        assert_eq!(
+            resolve(
+                &ctx,
+                WorldstateSyndicateType::ZarimanSyndicate,
+                "TierATableBRewards",
+                (50, 55),
+                false
+            )
+            .as_deref(),
+            Some("Voidplume Down")
+        );
+        assert_eq!(
+            resolve(
+                &ctx,
+                WorldstateSyndicateType::EntratiLabSyndicate,
+                "TierBTableARewards",
+                (55, 60),
+                false
+            )
+            .as_deref(),
+            Some("Vessel Capillaries")
+        );
+        assert_eq!(
+            resolve(
+                &ctx,
+                WorldstateSyndicateType::HexSyndicate,
+                "TierCTableCRewards",
+                (65, 70),
+                false
+            )
+            .as_deref(),
+            Some("Efervon Sample")
+        );
+        assert_eq!(
+            resolve(
+                &ctx,
+                WorldstateSyndicateType::HexSyndicate,
+                "TierCTableARewards",
+                (65, 70),
+                false
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_bounty_labels() {
+        let ctx = context();
+
+        // One bounty of every variant, by the label it has in the drop tables
+        let variants = [
+            (
+                "Level 40 - 60 Cetus Bounty",
+                (40, 60),
+                WorldstateSyndicateType::CetusSyndicate,
+                "TierATableARewards",
+                false,
+                "Cetus Wisp",
+            ),
+            (
+                "Level 40 - 60 Ghoul Bounty",
+                (40, 60),
+                WorldstateSyndicateType::CetusSyndicate,
+                "GhoulBountyTableARewards",
+                false,
+                "Gara Neuroptics",
+            ),
+            (
+                "Level 50 - 70 Narmer Bounty",
+                (50, 70),
+                WorldstateSyndicateType::CetusSyndicate,
+                "TierNarmerTableBRewards",
+                false,
+                "Eternal War",
+            ),
+            (
+                "Level 50 - 70 Orb Vallis Bounty",
+                (50, 70),
+                WorldstateSyndicateType::SolarisSyndicate,
+                "TierETableBRewards",
+                false,
+                "Toroid",
+            ),
+            (
+                "Level 50 - 70 Narmer Bounty",
+                (50, 70),
+                WorldstateSyndicateType::SolarisSyndicate,
+                "TierNarmerTableBRewards",
+                false,
+                "Father",
+            ),
+            (
+                "Level 30 - 40 Cambion Drift Bounty",
+                (30, 40),
+                WorldstateSyndicateType::EntratiSyndicate,
+                "TierCTableCRewards",
+                false,
+                "Scintillant",
+            ),
+            (
+                "Level 30 - 40 Isolation Vault",
+                (30, 40),
+                WorldstateSyndicateType::EntratiSyndicate,
+                "VaultBountyTierATableCRewards",
+                true,
+                "Theorem Contagion",
+            ),
+            (
+                "Level 50 - 55 Zariman Bounty",
+                (50, 55),
+                WorldstateSyndicateType::ZarimanSyndicate,
+                "TierATableBRewards",
+                false,
+                "Voidplume Down",
+            ),
+            (
+                "Level 55 - 60 Sanctum Bounty",
+                (55, 60),
+                WorldstateSyndicateType::EntratiLabSyndicate,
+                "TierBTableARewards",
+                false,
+                "Vessel Capillaries",
+            ),
+            (
+                "Level 65 - 70 Hex Bounty",
+                (65, 70),
+                WorldstateSyndicateType::HexSyndicate,
+                "TierCTableCRewards",
+                false,
+                "Efervon Sample",
+            ),
+        ];
+
+        let drops = std::str::from_utf8(DROPS).unwrap();
+
+        for (label, levels, syndicate_type, table, is_vault, item) in variants {
+            assert!(drops.contains(label), "{label} is missing from the drops");
+            assert_eq!(
+                resolve(&ctx, syndicate_type, table, levels, is_vault).as_deref(),
+                Some(item),
+                "{label}"
+            );
         }
+
+        // Only the exact label matches, not just the level range
+        assert_eq!(
+            resolve(
+                &ctx,
+                WorldstateSyndicateType::ZarimanSyndicate,
+                "TierATableBRewards",
+                (55, 60),
+                false
+            ),
+            None
+        );
+        assert_eq!(
+            resolve(
+                &ctx,
+                WorldstateSyndicateType::HexSyndicate,
+                "TierATableARewards",
+                (40, 60),
+                false
+            ),
+            None
+        );
     }
 }
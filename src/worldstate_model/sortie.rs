@@ -21,7 +21,7 @@ impl Resolve<ContextRef<'_>> for SortieUnmapped {
         Sortie {
             activation: self.activation,
             expiry: self.expiry,
-            boss: self.boss.resolve(ctx).cloned().map(|boss| boss.name),
+            boss: self.boss.resolve(ctx),
             extra_drops: self.extra_drops,
             id: self.id.oid,
             reward: self.reward.resolve(()),
@@ -39,7 +39,7 @@ impl Resolve<ContextRef<'_>> for SortieVariantUnmapped {
     fn resolve(self, ctx: ContextRef<'_>) -> Self::Output {
         SortieVariant {
             mission_type: self.mission_type.resolve(()),
-            modifier_type: self.modifier_type.resolve(ctx),
+            modifier: self.modifier_type.resolve(ctx),
             node: self.node.resolve(ctx).cloned(),
             tileset: self.tileset,
         }
@@ -84,3 +84,62 @@ pub struct SortieVariantUnmapped {
 
     pub tileset: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        context_sources::tests::fixture_sources,
+        core::Resolve,
+        target_types::{faction::Faction, worldstate_types::sortie::SortieModifier},
+        worldstate_model::sortie::SortieUnmapped,
+    };
+
+    const DATE: &str = r#"{ "$date": { "$numberLong": "1614776842000" } }"#;
+
+    #[test]
+    fn test_boss_and_modifiers() {
+        let mut sources = fixture_sources();
+        sources.worldstate_data.sortie_data = br#"{
+            "modifierTypes": { "SORTIE_MODIFIER_ARMOR": "Augmented Enemy Armor" },
+            "modifierDescriptions": {
+                "SORTIE_MODIFIER_ARMOR": "Enemies have Improved/Enhanced Armor."
+            },
+            "bosses": { "SORTIE_BOSS_VOR": { "name": "Captain Vor", "faction": "Grineer" } },
+            "modifiers": ["SORTIE_MODIFIER_ARMOR"]
+        }"#
+        .to_vec();
+
+        let ctx = sources.build().unwrap();
+
+        let sortie: SortieUnmapped = serde_json::from_str(&format!(
+            r#"{{
+                "_id": {{ "$oid": "1" }},
+                "Activation": {DATE},
+                "Expiry": {DATE},
+                "Reward": "/Lotus/Types/Game/MissionDecks/SortieRewards",
+                "Seed": 1,
+                "Boss": "SORTIE_BOSS_VOR",
+                "Variants": [{{
+                    "missionType": "MT_EXTERMINATION",
+                    "modifierType": "SORTIE_MODIFIER_ARMOR",
+                    "node": "SolNode1",
+                    "tileset": "GrineerGalleonTileset"
+                }}]
+            }}"#
+        ))
+        .unwrap();
+
+        let sortie = sortie.resolve(ctx.as_ref());
+        let boss = sortie.boss.unwrap();
+
+        assert_eq!(boss.faction, Faction::Grineer);
+        assert_eq!(boss.shard_color, None);
+        assert_eq!(
+            sortie.variants[0].modifier,
+            SortieModifier {
+                name: "Augmented Enemy Armor".to_owned(),
+                description: Some("Enemies have Improved/Enhanced Armor.".to_owned()),
+            }
+        );
+    }
+}
@@ -9,6 +9,7 @@ use crate::{
         SyndicateMissionDetails,
     },
     worldstate_model::{
+        BountyTable,
         Id,
         RotationalRewardContext,
         WorldstateSyndicateType,
@@ -152,12 +153,18 @@ impl Resolve<RotationalRewardContext<'_>> for JobUnmapped {
     type Output = SyndicateJob;
 
     fn resolve(self, ctx: RotationalRewardContext<'_>) -> Self::Output {
+        let BountyTable { kind, rotation } = BountyTable::parse(&self.rewards.path, self.is_vault);
+
         SyndicateJob {
             job_type: self.job_type.resolve(ctx.inner_ctx),
+            kind,
+            rotation,
             rewards: self.rewards.resolve(ctx).unwrap_or_default(),
             mastery_req: self.mastery_req,
             min_enemy_level: self.min_enemy_level,
             max_enemy_level: self.max_enemy_level,
+            stage_count: self.xp_amounts.len(),
+            total_standing: self.xp_amounts.iter().sum(),
             xp_amounts: self.xp_amounts,
             endless: self.endless,
             location_tag: self.location_tag,
@@ -2,11 +2,13 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    catalogue::CatalogueCategory,
     core::{ContextRef, InternalPath, Resolve, resolvable_string::ResolvableString, resolve_with},
     target_types::worldstate_types::void_trader::{
         ArrivedVoidTrader,
         DepartedVoidTrader,
         ShopItem,
+        ShopItemCategory,
         VoidTraderState,
     },
     worldstate_model::{Id, deserialize_mongo_date},
@@ -89,13 +91,17 @@ impl Resolve<ContextRef<'_>> for ArrivedVoidTraderUnmapped {
     type Output = ArrivedVoidTrader;
 
     fn resolve(self, ctx: ContextRef<'_>) -> Self::Output {
+        let shop = self.shop.resolve(ctx);
+
         ArrivedVoidTrader {
             id: self.id.oid,
             activation: self.activation,
             expiry: self.expiry,
             character: self.character,
             node: self.node.resolve(ctx),
-            shop: self.shop.resolve(ctx),
+            total_ducats: shop.iter().map(|item| item.prime_price).sum(),
+            total_credits: shop.iter().map(|item| item.regular_price).sum(),
+            shop,
         }
     }
 }
@@ -118,6 +124,8 @@ impl Resolve<ContextRef<'_>> for ShopItemUnmapped {
 
     fn resolve(self, ctx: ContextRef<'_>) -> Self::Output {
         ShopItem {
+            unique_name: self.item_type.path.clone(),
+            category: shop_item_category(&self.item_type.path, ctx),
             item_type: self.item_type.resolve(ctx),
             prime_price: self.prime_price,
             regular_price: self.regular_price,
@@ -125,3 +133,96 @@ impl Resolve<ContextRef<'_>> for ShopItemUnmapped {
         }
     }
 }
+
+/// Categorizes by the export the item comes from, falling back to the path if the catalogue isn't
+/// loaded or doesn't know the item.
+fn shop_item_category(path: &str, ctx: ContextRef<'_>) -> ShopItemCategory {
+    let category = ctx
+        .catalogue
+        .and_then(|catalogue| catalogue.get(path))
+        .map(|item| item.category);
+
+    match category {
+        Some(CatalogueCategory::Upgrade) => ShopItemCategory::Mod,
+        Some(CatalogueCategory::Arcane) => ShopItemCategory::Arcane,
+        Some(CatalogueCategory::Weapon) => ShopItemCategory::Weapon,
+        Some(CatalogueCategory::Warframe) => ShopItemCategory::Warframe,
+        Some(CatalogueCategory::Sentinel) => ShopItemCategory::Companion,
+        Some(CatalogueCategory::Customs | CatalogueCategory::Flavour) => ShopItemCategory::Cosmetic,
+        Some(CatalogueCategory::Relic) => ShopItemCategory::Relic,
+        Some(CatalogueCategory::Resource | CatalogueCategory::FusionBundle) => {
+            ShopItemCategory::Resource
+        },
+        Some(CatalogueCategory::Gear) => ShopItemCategory::Consumable,
+        Some(
+            CatalogueCategory::Recipe
+            | CatalogueCategory::Key
+            | CatalogueCategory::Drone
+            | CatalogueCategory::SortieReward,
+        ) => ShopItemCategory::Other,
+
+        None => match path {
+            path if path.contains("/BoosterPacks/") => ShopItemCategory::RelicPack,
+            path if path.contains("/Projections/") => ShopItemCategory::Relic,
+            path if path.contains("/Upgrades/Mods/") => ShopItemCategory::Mod,
+            path if path.contains("/Upgrades/CosmeticEnhancers/") => ShopItemCategory::Arcane,
+            path if path.contains("/Upgrades/Skins/")
+                || path.contains("/ShipDecos/")
+                || path.contains("/AvatarImages/")
+                || path.contains("/SuitCustomizations/") =>
+            {
+                ShopItemCategory::Cosmetic
+            },
+            path if path.contains("/Weapons/") => ShopItemCategory::Weapon,
+            path if path.contains("/Powersuits/") => ShopItemCategory::Warframe,
+            path if path.contains("/Sentinels/") => ShopItemCategory::Companion,
+            path if path.contains("/Restoratives/") => ShopItemCategory::Consumable,
+            path if path.contains("/MiscItems/") => ShopItemCategory::Resource,
+            _ => ShopItemCategory::Other,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        context_sources::tests::fixture_sources,
+        core::Resolve,
+        target_types::worldstate_types::void_trader::ShopItemCategory,
+        worldstate_model::void_trader::ArrivedVoidTraderUnmapped,
+    };
+
+    #[test]
+    fn test_shop() {
+        let ctx = fixture_sources().build().unwrap();
+
+        let trader: ArrivedVoidTraderUnmapped = serde_json::from_str(
+            r#"{
+                "_id": { "$oid": "1" },
+                "Activation": { "$date": { "$numberLong": "1614776842000" } },
+                "Expiry": { "$date": { "$numberLong": "1614949642000" } },
+                "Character": "Baro'Ki Teel",
+                "Node": "MercuryHUB",
+                "Manifest": [{
+                    "ItemType": "/Lotus/StoreItems/Upgrades/Mods/Warframe/Expert/AvatarPowerMaxModExpert",
+                    "PrimePrice": 350,
+                    "RegularPrice": 110000
+                }, {
+                    "ItemType": "/Lotus/StoreItems/Types/BoosterPacks/BaroTreasureBox",
+                    "PrimePrice": 50,
+                    "RegularPrice": 25000,
+                    "Limit": 1
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        let trader = trader.resolve(ctx.as_ref());
+
+        assert_eq!(trader.shop[0].category, ShopItemCategory::Mod);
+        assert_eq!(trader.shop[1].category, ShopItemCategory::RelicPack);
+        assert_eq!(trader.total_ducats, 400);
+        assert_eq!(trader.total_credits, 135_000);
+        assert_eq!(trader.limited_items().count(), 1);
+    }
+}